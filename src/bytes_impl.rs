@@ -0,0 +1,57 @@
+use super::{ SliceQueue, ReadableSliceQueue, WriteableSliceQueue };
+use core::cmp::min;
+use bytes::{ Buf, BufMut, buf::UninitSlice };
+
+
+/// The amount of bytes to reserve if `chunk_mut` is called without spare capacity
+const CHUNK_SIZE: usize = 64;
+
+
+impl Buf for SliceQueue<u8> {
+	/// Returns the amount of bytes stored in the queue
+	fn remaining(&self) -> usize {
+		self.len()
+	}
+	/// Returns the stored bytes as one contiguous slice
+	fn chunk(&self) -> &[u8] {
+		self.as_slice()
+	}
+	/// Discards the first `cnt` bytes
+	///
+	/// Parameters:
+	///  - `cnt`: The amount of bytes to discard
+	///
+	/// Panics if `cnt` is greater than `self.len()`
+	fn advance(&mut self, cnt: usize) {
+		assert!(cnt <= self.len(), "`cnt` is greater than `self.len()`");
+		self.drop_n(cnt).unwrap();
+	}
+}
+
+
+unsafe impl BufMut for SliceQueue<u8> {
+	/// Returns the amount of bytes that can be appended until the limit is reached
+	fn remaining_mut(&self) -> usize {
+		WriteableSliceQueue::remaining(self)
+	}
+	/// Appends the first `cnt` bytes of the last returned `chunk_mut`
+	///
+	/// Parameters:
+	///  - `cnt`: The amount of written bytes to append
+	///
+	/// Panics if `cnt` is greater than the spare capacity
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		assert!(cnt <= self.spare_capacity_mut().len(), "`cnt` is greater than the spare capacity");
+		let new_len = self.len() + cnt;
+		self.set_len(new_len)
+	}
+	/// Returns the spare capacity after the stored bytes (reserves up to `CHUNK_SIZE` bytes if
+	/// there is no spare capacity left and the limit has not been reached yet)
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		if self.spare_capacity_mut().is_empty() {
+			let to_reserve = min(WriteableSliceQueue::remaining(self), CHUNK_SIZE);
+			self.reserve_n(to_reserve).unwrap();
+		}
+		UninitSlice::uninit(self.spare_capacity_mut())
+	}
+}
@@ -0,0 +1,32 @@
+use core::fmt::{ Display, Formatter, Result as FmtResult };
+
+
+/// An error that is returned by the `try_*`-methods instead of panicking
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliceQueueError {
+	/// The operation would exceed the limit
+	LimitExceeded {
+		/// The limit of the `SliceQueue`
+		limit: usize,
+		/// The amount of elements the `SliceQueue` would contain after the operation
+		requested: usize
+	},
+	/// The operation requires more elements than available
+	NotEnoughElements {
+		/// The amount of elements stored in the `SliceQueue`
+		have: usize,
+		/// The amount of elements required by the operation
+		needed: usize
+	}
+}
+impl Display for SliceQueueError {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			SliceQueueError::LimitExceeded{ limit, requested } =>
+				write!(f, "{} elements requested but the limit is {}", requested, limit),
+			SliceQueueError::NotEnoughElements{ have, needed } =>
+				write!(f, "{} elements needed but only {} are available", needed, have)
+		}
+	}
+}
+impl core::error::Error for SliceQueueError {}
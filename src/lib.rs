@@ -2,16 +2,47 @@
 //! It allows you to
 //!  - efficiently push an arbitrary amount of elements to the back by either consuming them or by
 //!    cloning/copying them from a slice (if the type supports the `Clone`/`Copy` trait)
-//!  - communicate and enforce a limit on the amount of elements to store
+//!  - communicate and enforce a limit on the amount of elements to store (`push`, `push_n`,
+//!    `push_from` and the `try_*`-calls return the elements that could not be stored instead of
+//!    panicking; `push_in_place`, `extend`, `append`, `resize` and `get_or_extend` panic if the
+//!    limit would be exceeded)
 //!  - efficiently pop an arbitrary amount of elements from the front (optionally into a slice to
-//!    avoid uneccessary reallocations)
+//!    avoid uneccessary reallocations) or the back
 //!  - access the underlying buffer directly by using (range-)indices
+//!  - read it speculatively without consuming the elements using a `SliceQueueReader`
 //!  - dereference the `SliceQueue<T>` by propagating the `deref()`-call to the underlying `Vec<T>`
-//!  - access it using the `io::Read` and `io::Write` traits
+//!  - access it using the `io::Read` and `io::Write` traits (requires the `std` feature)
+//!  - (de-)serialize it using `serde` (requires the `serde` feature)
+//!  - read from and write to it using `bytes::Buf` and `bytes::BufMut` (requires the `bytes` feature)
+//!  - wipe secret data from it using `zeroize::Zeroize` (requires the `zeroize` feature)
+//!  - use it in `no_std`-environments with `alloc` (by disabling the `std` feature)
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+extern crate alloc;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+
+mod error;
 mod mem;
 mod queue;
+mod reader;
 mod traits;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "bytes")]
+mod bytes_impl;
+#[cfg(feature = "zeroize")]
+mod zeroize_impl;
 
+pub use error::SliceQueueError;
 pub use queue::{ SliceQueue, AutoShrinkMode };
-pub use traits::{ ReadableSliceQueue, WriteableSliceQueue };
\ No newline at end of file
+pub use reader::SliceQueueReader;
+pub use traits::{ ReadableSliceQueue, WriteableSliceQueue };
+pub use alloc::collections::TryReserveError;
\ No newline at end of file
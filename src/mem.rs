@@ -1,14 +1,15 @@
 
 #[cfg(feature = "unsafe_fast_code")]
-pub use self::usafe::{ drop_n, drain_n, drain_into };
+pub use self::usafe::{ pop, drop_n, drain_n, drain_append, drain_into, compact, clear, copy_from };
 
 #[cfg(not(feature = "unsafe_fast_code"))]
-pub use self::safe::{ drop_n, drain_n, drain_into };
+pub use self::safe::{ pop, drop_n, drain_n, drain_append, drain_into, compact, clear, copy_from };
 
 
 #[cfg(feature = "unsafe_fast_code")]
 mod usafe {
-	use std::{ ptr, mem };
+	use core::{ ptr, mem, slice };
+	use alloc::vec::Vec;
 	
 	/// Drops/deallocates all elements in `slice`
 	///
@@ -27,75 +28,147 @@ mod usafe {
 		}
 	}
 	
-	/// Removes `n` elements from `vec`'s beginning __without deallocating them__
+	/// Moves the first `n` elements after `head` out of `vec` by advancing `head` __without
+	/// deallocating them__
+	///
+	/// The elements before `head` are considered as moved out and must not be accessed anymore.
 	///
 	/// Parameters:
 	///  - `vec`: The vector to remove the elements from
+	///  - `head`: The offset of the first valid element in `vec`
 	///  - `n`: The amount of elements to remove
-	unsafe fn discard_n<T>(vec: &mut Vec<T>, n: usize) {
-		assert!(n <= vec.len(), "`n` is greater than `vec.len()`");
+	///
+	/// Returns __a pointer to the first removed element__
+	unsafe fn discard_n<T>(vec: &mut Vec<T>, head: &mut usize, n: usize) -> *mut T {
+		assert!(n <= vec.len() - *head, "`n` is greater than the amount of elements in `vec`");
 		
-		let remaining = vec.len() - n;
-		ptr::copy(vec[n..].as_ptr(), vec.as_mut_ptr(), remaining);
-		vec.set_len(remaining);
+		let ptr = vec.as_mut_ptr().add(*head);
+		*head += n;
+		ptr
 	}
 	
-	pub fn drop_n<T>(vec: &mut Vec<T>, n: usize) {
-		assert!(n <= vec.len(), "`n` is greater than `vec.len()`");
-		
-		// Drop the elements and discard them in `vec`
-		unsafe{ drop_in_place(&mut vec[..n]) }
-		unsafe{ discard_n(vec, n) }
+	pub fn pop<T>(vec: &mut Vec<T>, head: &mut usize) -> T {
+		unsafe{ ptr::read(discard_n(vec, head, 1)) }
 	}
 	
-	pub fn drain_n<T>(src: &mut Vec<T>, n: usize) -> Vec<T> {
-		assert!(n <= src.len(), "`n` is greater than `src.len()`");
-		
+	pub fn drop_n<T>(vec: &mut Vec<T>, head: &mut usize, n: usize) {
+		// Discard the elements in `vec` and drop them
+		let ptr = unsafe{ discard_n(vec, head, n) };
+		unsafe{ drop_in_place(slice::from_raw_parts_mut(ptr, n)) }
+	}
+	
+	pub fn drain_n<T>(src: &mut Vec<T>, head: &mut usize, n: usize) -> Vec<T> {
 		// Create new vector
 		let mut dst = Vec::with_capacity(n);
-		unsafe{ dst.set_len(n) }
 		
-		// Copy elements and discard them in `src`
-		unsafe{ ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), n) }
-		unsafe{ discard_n(src, n) }
+		// Discard elements in `src` and copy them
+		let ptr = unsafe{ discard_n(src, head, n) };
+		unsafe{ ptr::copy_nonoverlapping(ptr, dst.as_mut_ptr(), n) }
+		unsafe{ dst.set_len(n) }
 		
 		dst
 	}
 	
-	pub fn drain_into<T>(src: &mut Vec<T>, dst: &mut[T]) {
-		assert!(dst.len() <= src.len());
+	pub fn drain_append<T>(src: &mut Vec<T>, head: &mut usize, n: usize, dst: &mut Vec<T>) {
+		// Reserve the space in `dst`
+		dst.reserve(n);
+		let len = dst.len();
 		
-		// Drop all elements in `dst`
+		// Discard elements in `src` and copy them behind the last element in `dst`
+		let ptr = unsafe{ discard_n(src, head, n) };
+		unsafe{ ptr::copy_nonoverlapping(ptr, dst.as_mut_ptr().add(len), n) }
+		unsafe{ dst.set_len(len + n) }
+	}
+	
+	pub fn drain_into<T>(src: &mut Vec<T>, head: &mut usize, dst: &mut[T]) {
+		// Discard elements in `src` and drop all elements in `dst`
+		let ptr = unsafe{ discard_n(src, head, dst.len()) };
 		unsafe{ drop_in_place(dst) }
 		
-		// Copy elements and discard them in `src`
-		unsafe{ ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), dst.len()) }
-		unsafe{ discard_n(src, dst.len()) }
+		// Copy the discarded elements
+		unsafe{ ptr::copy_nonoverlapping(ptr, dst.as_mut_ptr(), dst.len()) }
+	}
+	
+	pub fn compact<T>(vec: &mut Vec<T>, head: &mut usize) {
+		assert!(*head <= vec.len(), "`head` is greater than `vec.len()`");
+		if *head == 0 { return }
+		
+		// Copy the remaining elements to the front
+		let remaining = vec.len() - *head;
+		unsafe{ ptr::copy(vec.as_ptr().add(*head), vec.as_mut_ptr(), remaining) }
+		unsafe{ vec.set_len(remaining) }
+		*head = 0;
+	}
+	
+	pub fn clear<T>(vec: &mut Vec<T>, head: &mut usize) {
+		assert!(*head <= vec.len(), "`head` is greater than `vec.len()`");
+		
+		// Reset `vec` first so that a panicking destructor results in a leak instead of a double-free
+		let (start, len) = (*head, vec.len());
+		unsafe{ vec.set_len(0) }
+		*head = 0;
+		
+		let ptr = unsafe{ vec.as_mut_ptr().add(start) };
+		unsafe{ drop_in_place(slice::from_raw_parts_mut(ptr, len - start)) }
+	}
+	
+	pub fn copy_from<T: Copy>(vec: &mut Vec<T>, src: &[T]) {
+		// Reserve the space and copy the elements behind the last element
+		vec.reserve(src.len());
+		let len = vec.len();
+		unsafe{ ptr::copy_nonoverlapping(src.as_ptr(), vec.as_mut_ptr().add(len), src.len()) }
+		unsafe{ vec.set_len(len + src.len()) }
 	}
 }
 
 
 #[cfg(not(feature = "unsafe_fast_code"))]
 mod safe {
-	pub fn drop_n<T>(src: &mut Vec<T>, n: usize) {
-		src.drain(..n);
+	use alloc::vec::Vec;
+	
+	/// _Info: Safe code cannot move an element out of `vec` without shifting the remaining
+	/// elements, so unlike the unsafe variant this is `O(n)` and `head` is never advanced._
+	pub fn pop<T>(vec: &mut Vec<T>, head: &mut usize) -> T {
+		vec.remove(*head)
+	}
+	
+	pub fn drop_n<T>(vec: &mut Vec<T>, head: &mut usize, n: usize) {
+		vec.drain(*head..*head + n);
+	}
+	
+	pub fn drain_n<T>(src: &mut Vec<T>, head: &mut usize, n: usize) -> Vec<T> {
+		src.drain(*head..*head + n).collect()
 	}
 	
-	pub fn drain_n<T>(src: &mut Vec<T>, n: usize) -> Vec<T> {
-		src.drain(..n).collect()
+	pub fn drain_append<T>(src: &mut Vec<T>, head: &mut usize, n: usize, dst: &mut Vec<T>) {
+		dst.extend(src.drain(*head..*head + n))
 	}
 	
-	pub fn drain_into<T>(src: &mut Vec<T>, dst: &mut[T]) {
-		let (mut src, dst) = (src.drain(..dst.len()), dst.iter_mut());
+	pub fn drain_into<T>(src: &mut Vec<T>, head: &mut usize, dst: &mut[T]) {
+		let (mut src, dst) = (src.drain(*head..*head + dst.len()), dst.iter_mut());
 		dst.for_each(|t| *t = src.next().unwrap());
 	}
+	
+	pub fn compact<T>(vec: &mut Vec<T>, head: &mut usize) {
+		vec.drain(..*head);
+		*head = 0;
+	}
+	
+	pub fn clear<T>(vec: &mut Vec<T>, head: &mut usize) {
+		vec.clear();
+		*head = 0;
+	}
+	
+	pub fn copy_from<T: Copy>(vec: &mut Vec<T>, src: &[T]) {
+		vec.extend_from_slice(src)
+	}
 }
 
 
 #[cfg(test)]
 mod tests {
-	use std::rc::Rc;
-	use super::{ drop_n, drain_n, drain_into };
+	use alloc::{ vec::Vec, rc::Rc };
+	use super::{ pop, drop_n, drain_n, drain_append, drain_into, compact, clear, copy_from };
 	
 	fn rc_vec(n: usize) -> Vec<Rc<usize>> {
 		let mut vec = Vec::new();
@@ -103,15 +176,31 @@ mod tests {
 		vec
 	}
 	
+	#[test]
+	fn test_pop() {
+		// Create RC-counted elements and clone them and test that the ref-count equals two
+		let base = rc_vec(42);
+		let (mut cloned, mut head) = (base.clone(), 0);
+		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
+		
+		// Pop 7 elements, compact `cloned` and validate the popped elements and the ref-counts
+		(0..7).for_each(|i| assert_eq!(*pop(&mut cloned, &mut head), i));
+		compact(&mut cloned, &mut head);
+		assert_eq!(cloned.len(), base.len() - 7);
+		base[..7].iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 1));
+		base[7..].iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
+	}
+	
 	#[test]
 	fn test_drop_n() {
 		// Create RC-counted elements and clone them and test that the ref-count equals two
 		let base = rc_vec(42);
-		let mut cloned = base.clone();
+		let (mut cloned, mut head) = (base.clone(), 0);
 		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 		
-		// Drop 7 elements in `cloned` and test the length and ref-counts
-		drop_n(&mut cloned, 7);
+		// Drop 7 elements in `cloned`, compact it and test the length and ref-counts
+		drop_n(&mut cloned, &mut head, 7);
+		compact(&mut cloned, &mut head);
 		assert_eq!(cloned.len(), base.len() - 7);
 		base[..7].iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 1));
 		base[7..].iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
@@ -121,11 +210,12 @@ mod tests {
 	fn test_drain_n() {
 		// Create RC-counted elements and cloned them and test that the ref-count equals two
 		let base = rc_vec(42);
-		let mut cloned = base.clone();
+		let (mut cloned, mut head) = (base.clone(), 0);
 		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 		
 		// Drain 7 elements and validate them and the remaining elements and the ref-counts
-		let drained = drain_n(&mut cloned, 7);
+		let drained = drain_n(&mut cloned, &mut head, 7);
+		compact(&mut cloned, &mut head);
 		assert_eq!(drained.len(), 7);
 		assert_eq!(cloned.len(), base.len() - 7);
 		
@@ -135,20 +225,40 @@ mod tests {
 		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 	}
 	
+	#[test]
+	fn test_drain_append() {
+		// Create RC-counted elements and clone them and test that the ref-count equals two
+		let base = rc_vec(42);
+		let (mut cloned, mut head, mut dst) = (base.clone(), 0, rc_vec(7));
+		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
+		
+		// Append 7 elements to `dst` and validate them and the remaining elements and the ref-counts
+		drain_append(&mut cloned, &mut head, 7, &mut dst);
+		compact(&mut cloned, &mut head);
+		assert_eq!(dst.len(), 14);
+		assert_eq!(cloned.len(), base.len() - 7);
+		
+		(0..14).for_each(|i| assert_eq!(*dst[i], i % 7));
+		(7..base.len()).for_each(|i| assert_eq!(*cloned[i - 7], i));
+		
+		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
+	}
+	
 	#[test]
 	fn test_drain_into() {
 		// Create RC-counted elements and cloned them and test that the ref-count equals two
 		let src_base = rc_vec(42);
 		let dst_base = rc_vec(7);
 		
-		let mut src = src_base.clone();
+		let (mut src, mut head) = (src_base.clone(), 0);
 		let mut dst = dst_base.clone();
 		
 		src_base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 		dst_base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 		
 		// Drain 7 elements into `dst` and validate them and the remaining elements and the ref-counts
-		drain_into(&mut src, &mut dst);
+		drain_into(&mut src, &mut head, &mut dst);
+		compact(&mut src, &mut head);
 		
 		assert_eq!(dst.len(), dst_base.len());
 		assert_eq!(src.len(), src_base.len() - 7);
@@ -159,4 +269,28 @@ mod tests {
 		src_base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
 		dst_base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 1));
 	}
+	
+	#[test]
+	fn test_clear() {
+		// Create RC-counted elements and clone them and test that the ref-count equals two
+		let base = rc_vec(42);
+		let (mut cloned, mut head) = (base.clone(), 0);
+		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 2));
+		
+		// Drop 7 elements and clear the remaining elements and validate the ref-counts
+		drop_n(&mut cloned, &mut head, 7);
+		clear(&mut cloned, &mut head);
+		assert!(cloned.is_empty());
+		assert_eq!(head, 0);
+		base.iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), 1));
+	}
+	
+	#[test]
+	fn test_copy_from() {
+		// Copy the elements twice and validate them
+		let (src, mut dst) = ((0..42).collect::<Vec<usize>>(), Vec::new());
+		copy_from(&mut dst, &src[..7]);
+		copy_from(&mut dst, &src[7..]);
+		assert_eq!(dst, src);
+	}
 }
\ No newline at end of file
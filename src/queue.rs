@@ -1,16 +1,30 @@
-use super::{ mem, ReadableSliceQueue, WriteableSliceQueue };
-use std::{
-	cmp::min, usize, io::{ Read, Write, Result as IoResult },
-	fmt::{ Debug, Formatter, Result as FmtResult },
-	ops::{ Index, IndexMut, Range, RangeFrom, RangeTo, RangeFull, RangeInclusive, RangeToInclusive }
+use super::{ mem, ReadableSliceQueue, WriteableSliceQueue, SliceQueueReader, SliceQueueError };
+use core::{
+	cmp::{ min, Ordering }, mem::take, slice::{ Iter, IterMut, Chunks, RChunks, ChunksExact, Windows, SliceIndex }, iter::FromIterator,
+	fmt::{ Debug, Formatter, Result as FmtResult }, hash::{ Hash, Hasher }, borrow::{ Borrow, BorrowMut },
+	ops::{ Index, IndexMut, RangeBounds, Range, RangeFrom, RangeTo, RangeFull, RangeInclusive, RangeToInclusive }
 };
+use alloc::{ vec::{ Vec, IntoIter, Drain, ExtractIf }, borrow::{ ToOwned, Cow }, collections::{ TryReserveError, VecDeque } };
+use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use std::io::{ Read, Write, IoSlice, Error as IoError, ErrorKind, Result as IoResult };
 
 
-#[derive(Copy, Clone, Debug, Ord, PartialOrd, PartialEq, Eq)]
+/// The default amount of elements a `SliceQueue` must exceed before `shrink_opportunistic` shrinks
+/// it; shrinking very small queues saves almost no memory but causes a reallocation each time
+const DEFAULT_SHRINK_THRESHOLD: usize = 4;
+/// The maximum amount of bytes `fill_from` reads at once if there is not more spare capacity
+/// available; this avoids huge allocations if `max` is very large
+#[cfg(feature = "std")]
+const FILL_CHUNK_SIZE: usize = 8 * 1024;
+
+
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, PartialEq, Eq)]
 pub enum AutoShrinkMode {
 	/// Shrinks the `SliceQueue` in 50% steps using `self.shrink_opportunistic`
 	///
 	/// __This mode is the default value__
+	#[default]
 	Opportunistic,
 	/// Immediately shrinks the `SliceQueue` to the amount of bytes used using `self.shrink_to_fit`
 	///
@@ -22,25 +36,21 @@ pub enum AutoShrinkMode {
 	/// `self.shrink_to_fit` methods accordingly if necessary.
 	Disabled
 }
-impl Default for AutoShrinkMode {
-	fn default() -> Self {
-		AutoShrinkMode::Opportunistic
-	}
-}
 
 
-#[derive(Default)]
 pub struct SliceQueue<T> {
 	backing: Vec<T>,
+	head: usize,
 	limit: usize,
-	auto_shrink_mode: AutoShrinkMode
+	auto_shrink_mode: AutoShrinkMode,
+	shrink_threshold: usize
 }
 impl<T> SliceQueue<T> {
 	/// Creates a new `SliceQueue`
 	///
 	/// Returns __the new `SliceQueue`__
 	pub fn new() -> Self {
-		SliceQueue{ backing: Vec::new(), limit: usize::MAX, auto_shrink_mode: Default::default() }
+		SliceQueue::from_backing(Vec::new(), usize::MAX)
 	}
 	/// Creates a new `SliceQueue` with a preallocated capacity `n`
 	///
@@ -49,7 +59,19 @@ impl<T> SliceQueue<T> {
 	///
 	/// Returns __the new `SliceQueue`__
 	pub fn with_capacity(n: usize) -> Self {
-		SliceQueue{ backing: Vec::with_capacity(n), limit: usize::MAX, auto_shrink_mode: Default::default() }
+		SliceQueue::from_backing(Vec::with_capacity(n), usize::MAX)
+	}
+	/// Creates a new `SliceQueue` with a preallocated capacity `n` like `SliceQueue::with_capacity`
+	/// but returns an error instead of aborting if the allocation fails
+	///
+	/// Parameters:
+	///  - `n`: The capacity to preallocate
+	///
+	/// Returns either __the new `SliceQueue`__ or __the allocation error__
+	pub fn try_with_capacity(n: usize) -> Result<Self, TryReserveError> {
+		let mut backing = Vec::new();
+		backing.try_reserve_exact(n)?;
+		Ok(SliceQueue::from_backing(backing, usize::MAX))
 	}
 	/// Creates a new `SliceQueue` with a predefined `limit` (the default limit is `usize::MAX`)
 	///
@@ -62,71 +84,1161 @@ impl<T> SliceQueue<T> {
 	/// Returns __the new `SliceQueue`__
 	pub fn with_limit(limit: usize) -> Self {
 		assert!(limit > 0, "`limit` is `0`");
-		SliceQueue{ backing: Vec::new(), limit, auto_shrink_mode: Default::default() }
+		SliceQueue::from_backing(Vec::new(), limit)
+	}
+	/// Creates a new `SliceQueue` with a preallocated capacity `n` and a predefined `limit`
+	///
+	/// _Info: If `n` is greater than `limit`, only `limit` elements are preallocated._
+	///
+	/// __Warning: Panics if `limit` is `0`__
+	///
+	/// Parameters:
+	///  - `n`: The capacity to preallocate
+	///  - `limit`: The limit to enforce. The limit indicates the maximum amount of elements that
+	///    can be stored by `self`.
+	///
+	/// Returns __the new `SliceQueue`__
+	pub fn with_capacity_and_limit(n: usize, limit: usize) -> Self {
+		assert!(limit > 0, "`limit` is `0`");
+		SliceQueue::from_backing(Vec::with_capacity(min(n, limit)), limit)
+	}
+	/// Creates a new `SliceQueue` by concatenating the elements of `slices`
+	///
+	/// Parameters:
+	///  - `slices`: The slices containing the elements to clone
+	///
+	/// Returns __the new `SliceQueue`__ with a capacity that matches the total amount of elements
+	pub fn from_slices(slices: &[&[T]]) -> Self where T: Clone {
+		SliceQueue::from(slices.concat())
+	}
+	/// Creates a new `SliceQueue` with a predefined `limit` from the elements yielded by `iter`
+	///
+	/// _Info: If `iter` yields more than `limit` elements, the surplus elements are discarded (i.e.
+	/// they are not pulled from `iter`) instead of panicking._
+	///
+	/// __Warning: Panics if `limit` is `0`__
+	///
+	/// Parameters:
+	///  - `iter`: The iterator yielding the elements to collect
+	///  - `limit`: The limit to enforce. The limit indicates the maximum amount of elements that
+	///    can be stored by `self`.
+	///
+	/// Returns __the new `SliceQueue`__
+	pub fn from_iter_with_limit<I: IntoIterator<Item = T>>(iter: I, limit: usize) -> Self {
+		let mut slice_queue = SliceQueue::with_limit(limit);
+		slice_queue.push_from_iter(iter);
+		slice_queue
+	}
+	
+	
+	/// Sets the auto-shrink mode
+	///
+	/// This mode specifies how the `SliceQueue` should behave if elements are consumed (e.g. by
+	/// `pop*`, `drop_n` or `truncate`); use `AutoShrinkMode::Disabled` to disable automatic
+	/// shrinking entirely
+	///
+	/// Parameters:
+	///  - `mode`: The auto-shrink mode to use
+	pub fn set_auto_shrink_mode(&mut self, mode: AutoShrinkMode) {
+		self.auto_shrink_mode = mode
+	}
+	/// The auto-shrink mode currently used
+	///
+	/// Returns __the current auto-shrink mode__ (the default mode is
+	/// `AutoShrinkMode::Opportunistic`)
+	pub fn auto_shrink_mode(&self) -> AutoShrinkMode {
+		self.auto_shrink_mode
+	}
+	/// Sets the minimum amount of elements that must be exceeded before `self.shrink_opportunistic`
+	/// shrinks the allocated capacity (the default threshold is `4`)
+	///
+	/// _Info: The default threshold avoids reallocating tiny queues where shrinking would save
+	/// almost no memory; use a high threshold to effectively disable opportunistic shrinking for
+	/// workloads with small elements._
+	///
+	/// Parameters:
+	///  - `min_len`: The new threshold
+	pub fn set_shrink_threshold(&mut self, min_len: usize) {
+		self.shrink_threshold = min_len
+	}
+	/// The threshold currently used by `self.shrink_opportunistic`
+	///
+	/// Returns __the current shrink threshold__ (the default threshold is `4`)
+	pub fn shrink_threshold(&self) -> usize {
+		self.shrink_threshold
+	}
+	
+	
+	/// Sets a new limit (the default limit is `usize::MAX`)
+	///
+	/// _Info: The limit is only enforced during the `push*`-calls. If the current length exceeds
+	/// the new limit, nothing happens until a `push*`-call would exceed the limit._
+	///
+	/// __Warning: Panics if `limit` is `0`__
+	///
+	/// Parameters:
+	///  - `limit`: The new limit to enforce. The limit indicates the maximum amount of elements
+	///    that can be stored by `self`.
+	pub fn set_limit(&mut self, limit: usize) {
+		assert!(limit > 0, "`limit` is `0`");
+		self.limit = limit
+	}
+	/// The current limit
+	///
+	/// Returns __the current size-limit of `self`__
+	pub fn limit(&self) -> usize {
+		self.limit
+	}
+	/// Removes the limit by resetting it to `usize::MAX`
+	pub fn set_unlimited(&mut self) {
+		self.limit = usize::MAX
+	}
+	/// Checks if a limit is set
+	///
+	/// Returns _`true`_ if `self.limit` is not `usize::MAX` or _`false`_ otherwise
+	pub fn is_limited(&self) -> bool {
+		self.limit != usize::MAX
+	}
+	
+	
+	/// The allocated capacity
+	///
+	/// Returns __the amount of elements `self` can hold without reallocating__
+	pub fn capacity(&self) -> usize {
+		self.backing.capacity()
+	}
+	/// The allocated capacity that is usable with respect to `self.limit`
+	///
+	/// _Info: `self.reserve_n` never grows the capacity beyond `self.limit`; however the
+	/// amortized growth of `push*`-calls may over-allocate, which is reverted by the next
+	/// opportunistic shrink._
+	///
+	/// Returns __the minimum of `self.capacity()` and `self.limit`__
+	pub fn capacity_for_limit(&self) -> usize {
+		min(self.capacity(), self.limit)
+	}
+	/// The ratio of the stored elements to the allocated capacity (the amount of spare slots is
+	/// returned by `self.reserved`)
+	///
+	/// Returns __`self.len() / self.capacity()`__ or _`1.0`_ if nothing is allocated
+	pub fn load_factor(&self) -> f64 {
+		match self.capacity() {
+			0 => 1.0,
+			capacity => self.len() as f64 / capacity as f64
+		}
+	}
+	/// The spare capacity after the stored elements as uninitialized memory
+	///
+	/// This is the memory `self` can grow into without reallocating; use it together with
+	/// `self.set_len` to fill `self` directly (e.g. from FFI) without requiring `T: Default`.
+	///
+	/// _Info: This function neither allocates nor compacts `self`; use `self.reserve_n` first if
+	/// you need more spare capacity._
+	///
+	/// Returns __the spare capacity__ (at most `self.remaining()` elements)
+	pub fn spare_capacity_mut(&mut self) -> &mut[MaybeUninit<T>] {
+		let remaining = self.remaining();
+		let spare = self.backing.spare_capacity_mut();
+		let len = min(spare.len(), remaining);
+		&mut spare[..len]
+	}
+	/// Forces the amount of stored elements to `new_len`
+	///
+	/// # Safety
+	/// __Warning: `new_len` must not exceed `self.len() + self.spare_capacity_mut().len()` and all
+	/// elements in `self.len()..new_len` must have been initialized (e.g. by writing them into
+	/// `self.spare_capacity_mut()`). If `new_len` is smaller than `self.len()`, the truncated
+	/// elements are leaked instead of being dropped. Violating this contract results in undefined
+	/// behaviour.__
+	///
+	/// Parameters:
+	///  - `new_len`: The new amount of stored elements
+	pub unsafe fn set_len(&mut self, new_len: usize) {
+		debug_assert!(new_len <= self.len() + self.spare_capacity_mut().len(), "`new_len` exceeds the spare capacity");
+		self.backing.set_len(self.head + new_len);
+	}
+	
+	
+	/// Shrinks the allocated capacity if less than it's half is used or the allocated capacity is
+	/// greater than `self.limit`
+	///
+	/// _Info: Nothing happens unless more than `self.shrink_threshold()` elements are stored._
+	pub fn shrink_opportunistic(&mut self) {
+		// Compute the half capacity
+		let half_capacity = if self.backing.capacity() == 0 { 0 }
+			else { self.backing.capacity() / 2 };
+		
+		// Resize the backing if the used space is smaller than the half capacity
+		if self.len() > self.shrink_threshold && (self.len() <= half_capacity || self.backing.capacity() > self.limit) { self.shrink_to_fit() }
+	}
+	/// Shrinks the allocated capacity as much as possible
+	pub fn shrink_to_fit(&mut self) {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.shrink_to_fit()
+	}
+	/// Shrinks the allocated capacity to `min_capacity` or `self.len()`, whichever is larger
+	///
+	/// _Info: This does not affect the auto-shrink mode, so a subsequent auto-shrink may still
+	/// shrink the allocated capacity below `min_capacity`; use `AutoShrinkMode::Disabled` if you
+	/// want to keep the capacity._
+	///
+	/// Parameters:
+	///  - `min_capacity`: The minimum capacity to keep
+	pub fn shrink_to(&mut self, min_capacity: usize) {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.shrink_to(min_capacity)
+	}
+	/// Performs the auto-shrink action specified by `self.auto_shrink_mode`
+	pub fn auto_shrink(&mut self) {
+		match self.auto_shrink_mode {
+			AutoShrinkMode::Opportunistic => self.shrink_opportunistic(),
+			AutoShrinkMode::Aggressive => self.shrink_to_fit(),
+			AutoShrinkMode::Disabled => ()
+		}
+	}
+	
+	
+	/// Drops all elements
+	///
+	/// _Info: This does not shrink the allocated capacity; use `self.shrink_to_fit` if you want to
+	/// release the memory._
+	pub fn clear(&mut self) {
+		mem::clear(&mut self.backing, &mut self.head)
+	}
+	/// Shortens `self` to the first `len` elements and drops the remaining elements
+	///
+	/// Does nothing if `len` is greater or equal to `self.len()`
+	///
+	/// Parameters:
+	///  - `len`: The amount of elements to keep
+	pub fn truncate(&mut self, len: usize) {
+		if len >= self.len() { return }
+		
+		// Drop the elements and shrink
+		self.backing.truncate(self.head + len);
+		self.compact_opportunistic();
+		self.auto_shrink();
+	}
+	/// Drops the first elements so that only the last `max` elements are kept
+	///
+	/// Unlike `self.truncate`, which keeps the first elements, this keeps the most recent
+	/// elements. Does nothing if `max` is greater or equal to `self.len()`
+	///
+	/// Parameters:
+	///  - `max`: The amount of elements to keep
+	pub fn trim_to(&mut self, max: usize) {
+		if max >= self.len() { return }
+		
+		// Drop the elements and shrink
+		let to_drop = self.len() - max;
+		mem::drop_n(&mut self.backing, &mut self.head, to_drop);
+		self.compact_opportunistic();
+		self.auto_shrink();
+	}
+	/// Resizes `self` to `new_len` elements by either appending clones of `value` or by
+	/// truncating `self`
+	///
+	/// __Warning: Panics if `new_len` is greater than `self.len()` and `self.limit`__
+	///
+	/// Parameters:
+	///  - `new_len`: The new amount of elements
+	///  - `value`: The value to clone if new elements are appended
+	pub fn resize(&mut self, new_len: usize, value: T) where T: Clone {
+		if new_len <= self.len() { return self.truncate(new_len) }
+		assert!(new_len <= self.limit, "`new_len` is larger than `self.limit`");
+		
+		self.make_room(new_len - self.len());
+		self.backing.resize(self.head + new_len, value)
+	}
+	/// Resizes `self` to `new_len` elements like `self.resize` but returns an error instead of
+	/// panicking if `new_len` is greater than `self.limit`
+	///
+	/// Parameters:
+	///  - `new_len`: The new amount of elements
+	///  - `value`: The value to clone if new elements are appended
+	///
+	/// Returns either __`Ok(())`__ or __`Err(SliceQueueError::LimitExceeded)`__ if `new_len` is
+	/// greater than `self.len()` and `self.limit`
+	pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), SliceQueueError> where T: Clone {
+		if new_len > self.len() && new_len > self.limit {
+			return Err(SliceQueueError::LimitExceeded{ limit: self.limit, requested: new_len })
+		}
+		self.resize(new_len, value);
+		Ok(())
+	}
+	/// Resizes `self` to `new_len` elements by either appending the values returned by `f` or by
+	/// truncating `self`
+	///
+	/// __Warning: Panics if `new_len` is greater than `self.len()` and `self.limit`__
+	///
+	/// Parameters:
+	///  - `new_len`: The new amount of elements
+	///  - `f`: The function that creates the values for new elements
+	pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) {
+		if new_len <= self.len() { return self.truncate(new_len) }
+		assert!(new_len <= self.limit, "`new_len` is larger than `self.limit`");
+		
+		self.make_room(new_len - self.len());
+		self.backing.resize_with(self.head + new_len, f)
+	}
+	/// Splits `self` into two at the given index
+	///
+	/// __Warning: Panics if `at` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `at`: The index to split at; `self` keeps the elements in `[0, at)`
+	///
+	/// Returns __a new `SliceQueue` containing the elements in `[at, len)`__ which inherits
+	/// `self.limit`, the auto-shrink mode and the shrink threshold
+	pub fn split_off(&mut self, at: usize) -> Self {
+		assert!(at <= self.len(), "`at` is greater than `self.len()`");
+		
+		// Split the elements off and shrink
+		let backing = self.backing.split_off(self.head + at);
+		self.compact_opportunistic();
+		self.auto_shrink();
+		
+		let mut slice_queue = SliceQueue::from_backing(backing, self.limit);
+		slice_queue.copy_settings_from(self);
+		slice_queue
+	}
+	/// Splits `self` into two at the given index like `self.split_off` but returns an error
+	/// instead of panicking if `at` is greater than `self.len()`
+	///
+	/// Parameters:
+	///  - `at`: The index to split at; `self` keeps the elements in `[0, at)`
+	///
+	/// Returns either __a new `SliceQueue` containing the elements in `[at, len)`__ or
+	/// __`Err(SliceQueueError::NotEnoughElements)`__ if `at` is greater than `self.len()`
+	pub fn try_split_off(&mut self, at: usize) -> Result<Self, SliceQueueError> {
+		if at > self.len() { return Err(SliceQueueError::NotEnoughElements{ have: self.len(), needed: at }) }
+		Ok(self.split_off(at))
+	}
+	/// Moves all elements from `other` to the end of `self`, leaving `other` empty
+	///
+	/// _Info: `other` keeps its allocated capacity._
+	///
+	/// __Warning: Panics if `self.limit` would be exceeded__
+	///
+	/// Parameters:
+	///  - `other`: The `SliceQueue` to move the elements from
+	pub fn append(&mut self, other: &mut Self) {
+		self.check_capacity(other.len());
+		
+		// Move the elements
+		mem::compact(&mut other.backing, &mut other.head);
+		self.make_room(other.len());
+		self.backing.append(&mut other.backing);
+	}
+	/// Moves all elements from `other` to the end of `self` like `self.append` but returns an
+	/// error instead of panicking if `self.limit` would be exceeded
+	///
+	/// _Info: If an error is returned, neither `self` nor `other` are modified._
+	///
+	/// Parameters:
+	///  - `other`: The `SliceQueue` to move the elements from
+	///
+	/// Returns either __`Ok(())`__ or __`Err(SliceQueueError::LimitExceeded)`__ if `self.limit`
+	/// would be exceeded
+	pub fn try_append(&mut self, other: &mut Self) -> Result<(), SliceQueueError> {
+		if !self.has_capacity(other.len()) {
+			let requested = self.len().saturating_add(other.len());
+			return Err(SliceQueueError::LimitExceeded{ limit: self.limit, requested })
+		}
+		self.append(other);
+		Ok(())
+	}
+	/// Appends `element` at the end and evicts the first element if `self.limit` has been reached
+	///
+	/// This turns `self` into a bounded buffer that always contains the most recent elements.
+	///
+	/// __Warning: Panics if `self.len()` exceeds `self.limit` (e.g. because the limit has been
+	/// lowered using `self.set_limit`); in this case a single eviction would not suffice to keep
+	/// `self` within its limit__
+	///
+	/// Parameters:
+	///  - `element`: The element to append at the end
+	///
+	/// Returns either _`Some(evicted)`_ if the first element was evicted to make room for
+	/// `element` or _`None`_ otherwise
+	pub fn push_overwrite(&mut self, element: T) -> Option<T> {
+		assert!(self.len() <= self.limit, "`self.len()` exceeds `self.limit`");
+		
+		// Evict the first element if necessary
+		let evicted = match self.remaining() {
+			0 => Some(mem::pop(&mut self.backing, &mut self.head)),
+			_ => None
+		};
+		self.compact_opportunistic();
+		
+		// Append the element
+		self.make_room(1);
+		self.backing.push(element);
+		evicted
+	}
+	/// Appends the elements yielded by `iter` at the end until either `iter` is exhausted or
+	/// `self.limit` is reached
+	///
+	/// Unlike `extend`, this function does not panic if `self.limit` is reached. No element is
+	/// pulled from `iter` once `self.limit` is reached, so you can pass `iter.by_ref()` to keep
+	/// the elements that could not be appended.
+	///
+	/// Parameters:
+	///  - `iter`: The iterator yielding the elements to append
+	///
+	/// Returns __the amount of elements appended__
+	pub fn push_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+		// Reserve the space for the elements expected
+		let iter = iter.into_iter().take(self.remaining());
+		let expected = iter.size_hint().0;
+		self.make_room(expected);
+		self.backing.reserve(expected);
+		
+		// Push the elements
+		let len = self.len();
+		iter.for_each(|element| {
+			self.make_room(1);
+			self.backing.push(element)
+		});
+		self.len() - len
+	}
+	/// Appends the elements yielded by `iter` at the end until `self.limit` is reached and
+	/// collects the remaining elements
+	///
+	/// Unlike `extend`, this function does not panic if `self.limit` is reached and unlike
+	/// `self.push_from_iter`, it always drains `iter` so that no element is lost.
+	///
+	/// Parameters:
+	///  - `iter`: The iterator yielding the elements to append
+	///
+	/// Returns __the elements that could not be appended__ (in their original order)
+	pub fn extend_within_limit<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Vec<T> {
+		let mut iter = iter.into_iter();
+		self.push_from_iter(iter.by_ref());
+		iter.collect()
+	}
+	
+	/// Inserts `element` at `index` and shifts all elements after it to the right
+	///
+	/// __Warning: Panics if `index` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `index`: The index to insert `element` at
+	///  - `element`: The element to insert
+	///
+	/// Returns either __`Ok(())`__ if the element was inserted successfully or __`Err(element)`__
+	/// if `element` was not inserted because `self.limit` would have been exceeded
+	pub fn insert(&mut self, index: usize, element: T) -> Result<(), T> {
+		assert!(index <= self.len(), "`index` is greater than `self.len()`");
+		if !self.has_capacity(1) { return Err(element) }
+		
+		self.make_room(1);
+		self.backing.insert(self.head + index, element);
+		Ok(())
+	}
+	/// Removes the element at `index` and shifts all elements after it to the left
+	///
+	/// __Warning: Panics if `index` is greater or equal to `self.len()`__
+	///
+	/// Parameters:
+	///  - `index`: The index of the element to remove
+	///
+	/// Returns __the removed element__
+	pub fn remove(&mut self, index: usize) -> T {
+		assert!(index < self.len(), "`index` is greater or equal to `self.len()`");
+		
+		// Remove the element and shrink
+		let element = self.backing.remove(self.head + index);
+		self.compact_opportunistic();
+		self.auto_shrink();
+		element
+	}
+	/// Removes the first element that is equal to `x` and shifts all elements after it to the left
+	///
+	/// Parameters:
+	///  - `x`: The element to remove
+	///
+	/// Returns __`true` if an element was removed__ or __`false` if `x` was not found__ (in this
+	/// case `self` remains unchanged)
+	pub fn remove_first(&mut self, x: &T) -> bool where T: PartialEq {
+		match self.as_slice().iter().position(|element| element == x) {
+			Some(index) => { self.remove(index); true },
+			None => false
+		}
+	}
+	/// Removes the element at `index` and replaces it with the last element
+	///
+	/// This does not preserve the order of the elements but is `O(1)`.
+	///
+	/// __Warning: Panics if `index` is greater or equal to `self.len()`__
+	///
+	/// Parameters:
+	///  - `index`: The index of the element to remove
+	///
+	/// Returns __the removed element__
+	pub fn swap_remove(&mut self, index: usize) -> T {
+		assert!(index < self.len(), "`index` is greater or equal to `self.len()`");
+		
+		// Remove the element and shrink
+		let element = self.backing.swap_remove(self.head + index);
+		self.compact_opportunistic();
+		self.auto_shrink();
+		element
+	}
+	/// Retains only the elements for which `f` returns `true` and drops the others
+	///
+	/// The order of the retained elements is preserved.
+	///
+	/// Parameters:
+	///  - `f`: The predicate that decides if an element is retained
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.retain(f);
+		self.auto_shrink();
+	}
+	/// Retains only the elements for which `f` returns `true` and drops the others like
+	/// `self.retain` but passes a mutable reference to `f`
+	///
+	/// Parameters:
+	///  - `f`: The predicate that decides if an element is retained and may modify it
+	pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.retain_mut(f);
+		self.auto_shrink();
+	}
+	/// Removes consecutive duplicate elements so that only the first element of each run is kept
+	pub fn dedup(&mut self) where T: PartialEq {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.dedup();
+		self.auto_shrink();
+	}
+	/// Removes consecutive elements that resolve to the same key so that only the first element of
+	/// each run is kept
+	///
+	/// Parameters:
+	///  - `key`: The function that computes the key for an element
+	pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, key: F) {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.dedup_by_key(key);
+		self.auto_shrink();
+	}
+	/// Removes the elements in `range` and returns them as iterator
+	///
+	/// The whole range is removed even if the iterator is only partially consumed.
+	///
+	/// _Info: Because the iterator borrows `self`, no auto-shrink is performed._
+	///
+	/// __Warning: Panics if `range` is out of bounds__
+	///
+	/// Parameters:
+	///  - `range`: The range of elements to remove
+	///
+	/// Returns __an iterator that yields the removed elements__
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.drain(range)
+	}
+	/// Removes the elements in `range` and returns them in their original order
+	///
+	/// Unlike `self.drain`, this collects the removed elements and performs an auto-shrink.
+	///
+	/// __Warning: Panics if `range` is out of bounds__
+	///
+	/// Parameters:
+	///  - `range`: The range of elements to remove
+	///
+	/// Returns __the removed elements__
+	pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) -> Vec<T> {
+		let elements = self.drain(range).collect();
+		self.auto_shrink();
+		elements
+	}
+	/// Removes the elements for which `f` returns `true` and returns them as iterator
+	///
+	/// The elements are visited and removed lazily; if the iterator is dropped before it is fully
+	/// consumed, the remaining elements are retained.
+	///
+	/// _Info: Because the iterator borrows `self`, no auto-shrink is performed._
+	///
+	/// Parameters:
+	///  - `f`: The predicate that decides if an element is removed
+	///
+	/// Returns __an iterator that yields the removed elements__
+	pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> ExtractIf<'_, T, F> {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.extract_if(.., f)
+	}
+	/// Consumes the leading elements as long as `pred` returns _`true`_
+	///
+	/// Parameters:
+	///  - `pred`: The predicate that decides if an element should be consumed
+	///
+	/// Returns __the consumed elements__ (which is empty if `pred` returned _`false`_ for the
+	/// first element)
+	pub fn pop_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+		// Move the matching prefix into `elements`
+		let to_consume = self.as_slice().iter().position(|element| !pred(element)).unwrap_or(self.len());
+		let elements = mem::drain_n(&mut self.backing, &mut self.head, to_consume);
+		
+		// Shrink and return elements
+		self.compact_opportunistic();
+		self.auto_shrink();
+		elements
+	}
+	/// Drops the leading elements as long as `pred` returns _`true`_
+	///
+	/// Parameters:
+	///  - `pred`: The predicate that decides if an element should be dropped
+	///
+	/// Returns __the amount of elements dropped__
+	pub fn discard_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+		// Drop the matching prefix
+		let to_drop = self.as_slice().iter().position(|element| !pred(element)).unwrap_or(self.len());
+		mem::drop_n(&mut self.backing, &mut self.head, to_drop);
+		
+		// Shrink and return the amount of elements dropped
+		self.compact_opportunistic();
+		self.auto_shrink();
+		to_drop
+	}
+	
+	/// Rotates the elements `n` places to the left so that the element at `n` becomes the first
+	/// element
+	///
+	/// __Warning: Panics if `n` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `n`: The amount of places to rotate
+	pub fn rotate_left(&mut self, n: usize) {
+		assert!(n <= self.len(), "`n` is greater than `self.len()`");
+		self.as_mut_slice().rotate_left(n)
+	}
+	/// Rotates the elements `n` places to the right so that the last `n` elements become the
+	/// first elements
+	///
+	/// __Warning: Panics if `n` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `n`: The amount of places to rotate
+	pub fn rotate_right(&mut self, n: usize) {
+		assert!(n <= self.len(), "`n` is greater than `self.len()`");
+		self.as_mut_slice().rotate_right(n)
+	}
+	/// Swaps the element at `i` with the element at `j`
+	///
+	/// __Warning: Panics if `i` or `j` is greater or equal to `self.len()`__
+	///
+	/// Parameters:
+	///  - `i`: The index of the first element
+	///  - `j`: The index of the second element
+	pub fn swap(&mut self, i: usize, j: usize) {
+		assert!(i < self.len(), "`i` is greater or equal to `self.len()`");
+		assert!(j < self.len(), "`j` is greater or equal to `self.len()`");
+		self.as_mut_slice().swap(i, j)
+	}
+	/// Reverses the order of the elements in place
+	pub fn reverse(&mut self) {
+		self.as_mut_slice().reverse()
+	}
+	/// Sorts the elements in place (this sort is stable)
+	pub fn sort(&mut self) where T: Ord {
+		self.as_mut_slice().sort()
+	}
+	/// Sorts the elements in place using the comparator `f` (this sort is stable)
+	///
+	/// Parameters:
+	///  - `f`: The comparator that returns the ordering of two elements
+	pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, f: F) {
+		self.as_mut_slice().sort_by(f)
+	}
+	/// Sorts the elements in place by the key extracted by `f` (this sort is stable)
+	///
+	/// Parameters:
+	///  - `f`: The function that extracts the key to sort by
+	pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+		self.as_mut_slice().sort_by_key(f)
+	}
+	/// Sorts the elements in place (this sort is unstable but usually faster than `self.sort`)
+	pub fn sort_unstable(&mut self) where T: Ord {
+		self.as_mut_slice().sort_unstable()
+	}
+	
+	
+	/// The stored elements as slice
+	///
+	/// This works independently of the `deref` feature.
+	///
+	/// Returns __a slice referencing all elements stored in `self`__
+	pub fn as_slice(&self) -> &[T] {
+		&self.backing[self.head..]
+	}
+	/// The stored elements as mutable slice
+	///
+	/// This works independently of the `deref` feature.
+	///
+	/// Returns __a mutable slice referencing all elements stored in `self`__
+	pub fn as_mut_slice(&mut self) -> &mut[T] {
+		&mut self.backing[self.head..]
+	}
+	/// The stored elements as copy-on-write slice
+	///
+	/// _Info: Since the stored elements are always contiguous, this always returns
+	/// `Cow::Borrowed` and never clones or compacts anything._
+	///
+	/// Returns __a `Cow` borrowing all elements stored in `self`__
+	pub fn as_cow(&self) -> Cow<'_, [T]> where T: Clone {
+		Cow::Borrowed(self.as_slice())
+	}
+	/// Moves the stored elements to the front of the allocated memory so that no consumed
+	/// elements precede them
+	///
+	/// This gives you explicit control over when the `O(n)`-compaction happens, which otherwise
+	/// happens opportunistically during consuming operations.
+	///
+	/// Returns __a mutable slice referencing all elements stored in `self`__
+	pub fn make_contiguous(&mut self) -> &mut[T] {
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.as_mut_slice()
+	}
+	/// Consumes `self` and returns the stored elements as `Vec<T>` (which is the inverse of
+	/// `SliceQueue::from(vec)`)
+	///
+	/// Returns __a `Vec<T>` containing all elements stored in `self`__
+	pub fn into_vec(self) -> Vec<T> {
+		Vec::from(self)
+	}
+	/// Moves all stored elements into a new `Vec<T>` and leaves `self` empty
+	///
+	/// Unlike `self.into_vec`, this does not consume `self` and keeps its allocated capacity so
+	/// that it can be reused (no auto-shrink is performed).
+	///
+	/// Returns __a `Vec<T>` containing all elements stored in `self`__
+	pub fn take_all(&mut self) -> Vec<T> {
+		let len = self.len();
+		let elements = mem::drain_n(&mut self.backing, &mut self.head, len);
+		self.compact_opportunistic();
+		elements
+	}
+	/// Consumes `self` and maps each element using `f` into a new `SliceQueue<U>`
+	///
+	/// The order of the elements, the limit, the auto-shrink mode and the shrink threshold are
+	/// preserved.
+	///
+	/// Parameters:
+	///  - `f`: The function that maps an element
+	///
+	/// Returns __the new `SliceQueue<U>`__ with a capacity that matches the amount of elements
+	pub fn transform<U, F: FnMut(T) -> U>(self, f: F) -> SliceQueue<U> {
+		let mut transformed = SliceQueue::from_backing(Vec::with_capacity(self.len()), self.limit);
+		transformed.copy_settings_from(&self);
+		transformed.backing.extend(self.into_iter().map(f));
+		transformed
+	}
+	
+	/// Gets an element or a subslice __without__ panicking if `index` is out of bounds
+	///
+	/// Parameters:
+	///  - `index`: The index of the element or the range of the subslice to get
+	///
+	/// Returns either _`Some(element_ref)`_/_`Some(subslice)`_ or _`None`_ if `index` is out of
+	/// bounds
+	pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+		self.as_slice().get(index)
+	}
+	/// Gets a mutable element or a mutable subslice __without__ panicking if `index` is out of
+	/// bounds
+	///
+	/// Parameters:
+	///  - `index`: The index of the element or the range of the subslice to get
+	///
+	/// Returns either _`Some(element_ref)`_/_`Some(subslice)`_ or _`None`_ if `index` is out of
+	/// bounds
+	pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+		self.as_mut_slice().get_mut(index)
+	}
+	/// Gets an element __without__ checking if `index` is in bounds
+	///
+	/// # Safety
+	/// __Warning: The caller must guarantee that `index < self.len()`; otherwise the behaviour is
+	/// undefined.__
+	///
+	/// Parameters:
+	///  - `index`: The index of the element to get
+	///
+	/// Returns __a reference to the element at `index`__
+	#[cfg(feature = "unsafe_fast_code")]
+	pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+		self.as_slice().get_unchecked(index)
+	}
+	/// Gets a mutable element __without__ checking if `index` is in bounds
+	///
+	/// # Safety
+	/// __Warning: The caller must guarantee that `index < self.len()`; otherwise the behaviour is
+	/// undefined.__
+	///
+	/// Parameters:
+	///  - `index`: The index of the element to get
+	///
+	/// Returns __a mutable reference to the element at `index`__
+	#[cfg(feature = "unsafe_fast_code")]
+	pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+		self.as_mut_slice().get_unchecked_mut(index)
+	}
+	/// Gets a mutable element and appends default elements up to and including `index` if
+	/// `index` is out of bounds
+	///
+	/// __Warning: Panics if `self.limit` would be exceeded__
+	///
+	/// Parameters:
+	///  - `index`: The index of the element to get
+	///
+	/// Returns __a mutable reference to the element at `index`__
+	pub fn get_or_extend(&mut self, index: usize) -> &mut T where T: Default {
+		if index >= self.len() {
+			let additional = index - self.len() + 1;
+			self.check_capacity(additional);
+			self.make_room(additional);
+			
+			let new_len = self.backing.len() + additional;
+			self.backing.resize_with(new_len, T::default);
+		}
+		&mut self.as_mut_slice()[index]
+	}
+	
+	
+	/// Take a look at the first element __without__ consuming it (an alias for `self.peek`
+	/// for `VecDeque`-compatibility)
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ if `self` is
+	/// empty
+	pub fn front(&self) -> Option<&T> {
+		self.peek()
+	}
+	/// Take a mutable look at the first element __without__ consuming it (an alias for
+	/// `self.peek_mut` for `VecDeque`-compatibility)
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ if `self` is
+	/// empty
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		self.peek_mut()
+	}
+	/// Take a look at the last element __without__ consuming it (an alias for `self.peek_back`
+	/// for `VecDeque`-compatibility)
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ if `self` is
+	/// empty
+	pub fn back(&self) -> Option<&T> {
+		self.peek_back()
+	}
+	/// Take a mutable look at the last element __without__ consuming it (an alias for
+	/// `self.peek_back_mut` for `VecDeque`-compatibility)
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ if `self` is
+	/// empty
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		self.peek_back_mut()
+	}
+	/// Splits the stored elements into the first element and the remaining elements
+	///
+	/// Returns either _`Some((first, remaining))`_ or _`None`_ if `self` is empty
+	pub fn split_first(&self) -> Option<(&T, &[T])> {
+		self.as_slice().split_first()
+	}
+	/// Splits the stored elements into the first element and the remaining elements mutably
+	///
+	/// Returns either _`Some((first, remaining))`_ or _`None`_ if `self` is empty
+	pub fn split_first_mut(&mut self) -> Option<(&mut T, &mut[T])> {
+		self.as_mut_slice().split_first_mut()
+	}
+	/// Splits the stored elements into the last element and the remaining elements
+	///
+	/// Returns either _`Some((last, remaining))`_ or _`None`_ if `self` is empty
+	pub fn split_last(&self) -> Option<(&T, &[T])> {
+		self.as_slice().split_last()
+	}
+	/// Splits the stored elements into the last element and the remaining elements mutably
+	///
+	/// Returns either _`Some((last, remaining))`_ or _`None`_ if `self` is empty
+	pub fn split_last_mut(&mut self) -> Option<(&mut T, &mut[T])> {
+		self.as_mut_slice().split_last_mut()
+	}
+	/// Splits the stored elements into two slices at `mid`
+	///
+	/// __Warning: Panics if `mid` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `mid`: The index to split at; the first slice contains the elements in `[0, mid)`
+	///
+	/// Returns __the elements in `[0, mid)` and the elements in `[mid, len)`__
+	pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+		assert!(mid <= self.len(), "`mid` is greater than `self.len()`");
+		self.as_slice().split_at(mid)
+	}
+	/// Splits the stored elements into two mutable slices at `mid`
+	///
+	/// __Warning: Panics if `mid` is greater than `self.len()`__
+	///
+	/// Parameters:
+	///  - `mid`: The index to split at; the first slice contains the elements in `[0, mid)`
+	///
+	/// Returns __the elements in `[0, mid)` and the elements in `[mid, len)`__
+	pub fn split_at_mut(&mut self, mid: usize) -> (&mut[T], &mut[T]) {
+		assert!(mid <= self.len(), "`mid` is greater than `self.len()`");
+		self.as_mut_slice().split_at_mut(mid)
+	}
+	
+	
+	/// Creates an iterator over the stored elements
+	///
+	/// Returns __an iterator that yields references to all elements from front to back__
+	pub fn iter(&self) -> Iter<'_, T> {
+		self.as_slice().iter()
+	}
+	/// Creates an iterator over the stored elements that allows modifying each element
+	///
+	/// Returns __an iterator that yields mutable references to all elements from front to back__
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+		self.as_mut_slice().iter_mut()
+	}
+	/// Applies `f` to each stored element in place from front to back
+	///
+	/// Parameters:
+	///  - `f`: The function that transforms an element
+	pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+		self.iter_mut().for_each(f)
+	}
+	/// Creates a non-consuming cursor over the stored elements
+	///
+	/// Returns __a `SliceQueueReader` that starts at the first element__
+	pub fn reader(&self) -> SliceQueueReader<'_, T> {
+		SliceQueueReader::new(self.as_slice())
+	}
+	/// Creates an iterator over the stored elements in chunks of `size` elements
+	///
+	/// _Info: If `self.len()` is not a multiple of `size`, the last chunk is shorter than `size`._
+	///
+	/// __Warning: Panics if `size` is `0`__
+	///
+	/// Parameters:
+	///  - `size`: The amount of elements per chunk
+	///
+	/// Returns __an iterator that yields the chunks from front to back__
+	pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+		self.as_slice().chunks(size)
+	}
+	/// Creates an iterator over the stored elements in chunks of `size` elements, starting at the
+	/// back
+	///
+	/// _Info: If `self.len()` is not a multiple of `size`, the last chunk (i.e. the front-most one)
+	/// is shorter than `size`._
+	///
+	/// __Warning: Panics if `size` is `0`__
+	///
+	/// Parameters:
+	///  - `size`: The amount of elements per chunk
+	///
+	/// Returns __an iterator that yields the chunks from back to front__
+	pub fn rchunks(&self, size: usize) -> RChunks<'_, T> {
+		self.as_slice().rchunks(size)
+	}
+	/// Creates an iterator over the stored elements in chunks of exactly `size` elements
+	///
+	/// _Info: If `self.len()` is not a multiple of `size`, the remaining elements are omitted and
+	/// can be retrieved using the iterator's `remainder` method._
+	///
+	/// __Warning: Panics if `size` is `0`__
+	///
+	/// Parameters:
+	///  - `size`: The amount of elements per chunk
+	///
+	/// Returns __an iterator that yields the chunks from front to back__
+	pub fn chunks_exact(&self, size: usize) -> ChunksExact<'_, T> {
+		self.as_slice().chunks_exact(size)
+	}
+	/// Creates an iterator over all overlapping windows of `size` elements
+	///
+	/// __Warning: Panics if `size` is `0`__
+	///
+	/// Parameters:
+	///  - `size`: The amount of elements per window
+	///
+	/// Returns __an iterator that yields the windows from front to back__ (which yields nothing if
+	/// `size` is greater than `self.len()`)
+	pub fn windows(&self, size: usize) -> Windows<'_, T> {
+		self.as_slice().windows(size)
+	}
+	
+	
+	/// Checks if `self` contains an element that is equal to `x`
+	///
+	/// Parameters:
+	///  - `x`: The element to search for
+	///
+	/// Returns either __`true`__ if `self` contains `x` or __`false`__ otherwise
+	pub fn contains(&self, x: &T) -> bool where T: PartialEq {
+		self.as_slice().contains(x)
+	}
+	/// Searches for the first element that matches `pred`
+	///
+	/// Parameters:
+	///  - `pred`: The predicate to match the elements against
+	///
+	/// Returns either _`Some(index)`_ with the index of the first matching element (relative to the
+	/// front) or _`None`_ if no element matched
+	pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+		self.iter().position(pred)
+	}
+	/// Searches for the first occurrence of `needle`
+	///
+	/// _Info: This is a naive search with a complexity of `O(self.len() * needle.len())`._
+	///
+	/// Parameters:
+	///  - `needle`: The sequence of elements to search for
+	///
+	/// Returns either _`Some(index)`_ with the index of the first occurrence of `needle` (relative
+	/// to the front; `0` if `needle` is empty) or _`None`_ if `needle` was not found
+	pub fn find(&self, needle: &[T]) -> Option<usize> where T: PartialEq {
+		match needle.len() {
+			0 => Some(0),
+			len => self.as_slice().windows(len).position(|window| window == needle)
+		}
+	}
+	/// Counts the stored elements that satisfy `pred`
+	///
+	/// Parameters:
+	///  - `pred`: The predicate to test each element with
+	///
+	/// Returns __the amount of elements for which `pred` returned `true`__
+	pub fn count_matching<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+		self.as_slice().iter().filter(|element| pred(element)).count()
+	}
+	/// Checks if the stored elements start with `needle`
+	///
+	/// Parameters:
+	///  - `needle`: The expected prefix
+	///
+	/// Returns __`true` if `needle` is a prefix of the stored elements__ (always `true` if `needle`
+	/// is empty)
+	pub fn starts_with(&self, needle: &[T]) -> bool where T: PartialEq {
+		self.as_slice().starts_with(needle)
+	}
+	/// Checks if the stored elements end with `needle`
+	///
+	/// Parameters:
+	///  - `needle`: The expected suffix
+	///
+	/// Returns __`true` if `needle` is a suffix of the stored elements__ (always `true` if `needle`
+	/// is empty)
+	pub fn ends_with(&self, needle: &[T]) -> bool where T: PartialEq {
+		self.as_slice().ends_with(needle)
+	}
+	/// Searches for `x` in the sorted elements using binary search
+	///
+	/// Parameters:
+	///  - `x`: The element to search for
+	///
+	/// Returns either _`Ok(index)`_ with the index of a matching element or _`Err(index)`_ with
+	/// the index where `x` could be inserted to keep the elements sorted (both relative to the
+	/// front)
+	pub fn binary_search(&self, x: &T) -> Result<usize, usize> where T: Ord {
+		self.as_slice().binary_search(x)
+	}
+	/// Searches the sorted elements using binary search and the comparator `f`
+	///
+	/// Parameters:
+	///  - `f`: The comparator that returns the ordering of an element relative to the target
+	///
+	/// Returns either _`Ok(index)`_ with the index of a matching element or _`Err(index)`_ with
+	/// the index where the target could be inserted to keep the elements sorted (both relative
+	/// to the front)
+	pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+		self.as_slice().binary_search_by(f)
 	}
-	
-	
-	/// Sets the auto-shrink mode
+	/// Finds the partition point of the elements that are partitioned according to `pred`
 	///
-	/// This mode specifies how the `SliceQueue` should behave if elements are consumed
+	/// The elements must be partitioned so that all elements for which `pred` returns `true`
+	/// precede all elements for which it returns `false` (e.g. if they are sorted); otherwise the
+	/// result is unspecified.
 	///
 	/// Parameters:
-	///  - `auto_shrink`: The auto-shrink mode to use
-	pub fn set_auto_shrink_mode(&mut self, mode: AutoShrinkMode) {
-		self.auto_shrink_mode = mode
-	}
-	/// The auto-shrink mode currently used
+	///  - `pred`: The predicate that partitions the elements
 	///
-	/// Returns _`true`_ if auto-shrink is enabled (which is the default state) or _`false`_ if it
-	/// was disabled
-	pub fn auto_shrink_mode(&self) -> AutoShrinkMode {
-		self.auto_shrink_mode
+	/// Returns __the index of the first element for which `pred` returns `false`__ (relative to the
+	/// front; `self.len()` if there is no such element)
+	pub fn partition_point<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+		self.as_slice().partition_point(pred)
 	}
 	
 	
-	/// Sets a new limit (the default limit is `usize::MAX`)
+	/// Creates a new `SliceQueue` that stores the elements in `backing` and uses the default
+	/// auto-shrink mode and shrink threshold
 	///
-	/// _Info: The limit is only enforced during the `push*`-calls. If the current length exceeds
-	/// the new limit, nothing happens until a `push*`-call would exceed the limit._
+	/// Parameters:
+	///  - `backing`: The elements to store
+	///  - `limit`: The limit to enforce
 	///
-	/// __Warning: Panics if `limit` is `0`__
+	/// Returns __the new `SliceQueue`__
+	fn from_backing(backing: Vec<T>, limit: usize) -> Self {
+		SliceQueue{ backing, head: 0, limit, auto_shrink_mode: Default::default(), shrink_threshold: DEFAULT_SHRINK_THRESHOLD }
+	}
+	/// Copies the limit, the auto-shrink mode and the shrink threshold of `other` into `self`
 	///
 	/// Parameters:
-	///  - `limit`: The new limit to enforce. The limit indicates the maximum amount of elements
-	///    that can be stored by `self`.
-	pub fn set_limit(&mut self, limit: usize) {
-		assert!(limit > 0, "`limit` is `0`");
-		self.limit = limit
+	///  - `other`: The `SliceQueue` to copy the settings from
+	fn copy_settings_from<U>(&mut self, other: &SliceQueue<U>) {
+		self.limit = other.limit;
+		self.auto_shrink_mode = other.auto_shrink_mode;
+		self.shrink_threshold = other.shrink_threshold;
 	}
-	/// The current limit
+	/// Moves the stored elements to the front of `self.backing` if the space occupied by already
+	/// consumed elements is at least half as large as the space occupied by the stored elements
 	///
-	/// Returns __the current size-limit of `self`__
-	pub fn limit(&self) -> usize {
-		self.limit
+	/// This ensures that consuming elements from the front is amortized `O(1)`.
+	fn compact_opportunistic(&mut self) {
+		if self.head > 0 && self.head >= self.len() / 2 { mem::compact(&mut self.backing, &mut self.head) }
 	}
-	
-	
-	/// Shrinks the allocated capacity if less than it's half is used or the allocated capacity is
-	/// greater than `self.limit`
-	pub fn shrink_opportunistic(&mut self) {
-		// Compute the half capacity
-		let half_capacity = if self.backing.capacity() == 0 { 0 }
-			else { self.backing.capacity() / 2 };
+	/// Moves the stored elements to the front of `self.backing` if there is not enough space left
+	/// to append `n` elements without reallocating
+	///
+	/// If `self.backing` has less than `self.len() / 2` spare elements after compacting, it also
+	/// reserves space for up to `self.len() / 2` elements (capped at `self.remaining()`); this
+	/// ensures that appending to a queue that is filled up to its capacity remains amortized
+	/// `O(1)` even if only a few elements have been consumed.
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements that will be appended
+	fn make_room(&mut self, n: usize) {
+		if self.backing.capacity() - self.backing.len() >= n || self.head == 0 { return }
 		
-		// Resize the backing if the used space is smaller than the half capacity
-		if self.len() > 4 && (self.len() <= half_capacity || self.backing.capacity() > self.limit) { self.backing.shrink_to_fit() }
+		// Compact and reserve enough headroom to amortize the next compaction
+		mem::compact(&mut self.backing, &mut self.head);
+		let headroom = min(n.max(self.len() / 2), self.remaining());
+		self.backing.reserve_exact(headroom);
 	}
-	/// Shrinks the allocated capacity as much as possible
-	pub fn shrink_to_fit(&mut self) {
-		self.backing.shrink_to_fit()
+	/// Checks if `additional` elements can be appended without exceeding `self.limit`
+	///
+	/// Parameters:
+	///  - `additional`: The amount of elements to append
+	///
+	/// Returns _`true`_ if `self.len() + additional` neither overflows nor exceeds `self.limit` or
+	/// _`false`_ otherwise
+	fn has_capacity(&self, additional: usize) -> bool {
+		self.len().checked_add(additional).is_some_and(|len| len <= self.limit)
 	}
-	/// Performs the auto-shrink action specified by `self.auto_shrink_mode`
-	pub fn auto_shrink(&mut self) {
-		match self.auto_shrink_mode {
-			AutoShrinkMode::Opportunistic => self.shrink_opportunistic(),
-			AutoShrinkMode::Aggressive => self.shrink_to_fit(),
-			AutoShrinkMode::Disabled => ()
-		}
+	/// Ensures that `additional` elements can be appended without exceeding `self.limit`
+	///
+	/// __Warning: Panics if `self.len() + additional` overflows or exceeds `self.limit`__
+	///
+	/// Parameters:
+	///  - `additional`: The amount of elements to append
+	fn check_capacity(&self, additional: usize) {
+		assert!(self.has_capacity(additional), "`self.limit` would be exceeded")
+	}
+	/// Compacts `self` and returns the backing `Vec` that contains exactly the stored elements
+	#[cfg(feature = "zeroize")]
+	pub(crate) fn compacted_backing_mut(&mut self) -> &mut Vec<T> {
+		mem::compact(&mut self.backing, &mut self.head);
+		&mut self.backing
 	}
 }
 
@@ -136,20 +1248,38 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 	///
 	/// Returns __the amount of elements stored in `self`__
 	fn len(&self) -> usize {
-		self.backing.len()
+		self.backing.len() - self.head
 	}
 	/// Checks if there are __no__ elements stored
 	///
 	/// Returns either __`true`__ if `self` is empty or __`false`__ otherwise
 	fn is_empty(&self) -> bool {
-		self.backing.is_empty()
+		self.len() == 0
 	}
 	
 	/// Take a look at the first element __without__ consuming it
 	///
 	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ otherwise
 	fn peek(&self) -> Option<&T> {
-		self.backing.first()
+		self.as_slice().first()
+	}
+	/// Take a mutable look at the first element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ otherwise
+	fn peek_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_slice().first_mut()
+	}
+	/// Take a look at the last element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ otherwise
+	fn peek_back(&self) -> Option<&T> {
+		self.as_slice().last()
+	}
+	/// Take a mutable look at the last element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ otherwise
+	fn peek_back_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_slice().last_mut()
 	}
 	/// Take a look at the first `n` elements __without__ consuming them
 	///
@@ -159,8 +1289,56 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
 	/// __`Err(element_refs)`__ if less elements were available
 	fn peek_n(&self, n: usize) -> Result<&[T], &[T]> {
-		if n <= self.len() { Ok(&self.backing[..n]) }
-			else { Err(&self.backing) }
+		if n <= self.len() { Ok(&self.as_slice()[..n]) }
+			else { Err(self.as_slice()) }
+	}
+	/// Take a mutable look at the first `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_n_mut(&mut self, n: usize) -> Result<&mut[T], &mut[T]> {
+		if n <= self.len() { Ok(&mut self.as_mut_slice()[..n]) }
+			else { Err(self.as_mut_slice()) }
+	}
+	/// Take a look at the last `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_back_n(&self, n: usize) -> Result<&[T], &[T]> {
+		if n <= self.len() { Ok(&self.as_slice()[self.len() - n..]) }
+			else { Err(self.as_slice()) }
+	}
+	/// Take a mutable look at the last `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_back_n_mut(&mut self, n: usize) -> Result<&mut[T], &mut[T]> {
+		let len = self.len();
+		if n <= len { Ok(&mut self.as_mut_slice()[len - n..]) }
+			else { Err(self.as_mut_slice()) }
+	}
+	/// Copies the first `dst.len()` elements into `dst` __without__ consuming them
+	///
+	/// _Info: This call never panics; if less elements are available, only the first
+	/// `self.len()` elements in `dst` are replaced and the remaining elements are left untouched._
+	///
+	/// Parameters:
+	///  - `dst`: The target slice to copy the elements into
+	///
+	/// Returns __the amount of elements copied__
+	fn copy_into(&self, dst: &mut[T]) -> usize where T: Copy {
+		let to_copy = min(self.len(), dst.len());
+		dst[..to_copy].copy_from_slice(&self.as_slice()[..to_copy]);
+		to_copy
 	}
 	
 	/// Consumes the first element and returns it
@@ -171,7 +1349,8 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 		match self.is_empty() {
 			true => Err(()),
 			false => {
-				let element = self.backing.remove(0);
+				let element = mem::pop(&mut self.backing, &mut self.head);
+				self.compact_opportunistic();
 				self.auto_shrink();
 				Ok(element)
 			}
@@ -187,15 +1366,50 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 	fn pop_n(&mut self, n: usize) -> Result<Vec<T>, Vec<T>> {
 		// Move elements into `elements`
 		let to_consume = min(self.len(), n);
-		let elements = mem::drain_n(&mut self.backing, to_consume);
+		let elements = mem::drain_n(&mut self.backing, &mut self.head, to_consume);
 		
 		// Shrink and return result
+		self.compact_opportunistic();
 		self.auto_shrink();
 		if to_consume == n { Ok(elements) }
 			else { Err(elements) }
 	}
+	/// Consumes up to `n` elements and returns them (all elements if less than `n` are available)
+	///
+	/// Parameters:
+	///  - `n`: The maximum amount of elements to consume
+	///
+	/// Returns __the consumed elements__
+	fn pop_n_max(&mut self, n: usize) -> Vec<T> {
+		match self.pop_n(n) {
+			Ok(elements) | Err(elements) => elements
+		}
+	}
+	/// Consumes the first `n` elements and appends them to `dst`
+	///
+	/// This allows you to reuse `dst` instead of allocating a new `Vec<T>` for each call.
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to consume
+	///  - `dst`: The vector to append the elements to
+	///
+	/// Returns either _`true`_ if `n` elements were appended or _`false`_ if less elements were
+	/// available (in this case nothing is consumed)
+	fn pop_n_append_to(&mut self, n: usize, dst: &mut Vec<T>) -> bool {
+		if n > self.len() { return false }
+		mem::drain_append(&mut self.backing, &mut self.head, n, dst);
+		
+		// Shrink
+		self.compact_opportunistic();
+		self.auto_shrink();
+		true
+	}
 	/// Consumes the first `dst.len()` and moves them into `dst`
 	///
+	/// _Info: This call never panics; if less elements are available, only the first
+	/// `element_count` elements in `dst` are replaced and the remaining elements are left
+	/// untouched._
+	///
 	/// Parameters:
 	///  - `dst`: The target to move the elements into
 	///
@@ -204,14 +1418,50 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 	fn pop_into(&mut self, dst: &mut[T]) -> Result<(), usize> {
 		// Move elements
 		let to_move = min(self.len(), dst.len());
-		mem::drain_into(&mut self.backing, &mut dst[..to_move]);
+		mem::drain_into(&mut self.backing, &mut self.head, &mut dst[..to_move]);
 		
 		// Shrink and return result
+		self.compact_opportunistic();
 		self.auto_shrink();
 		if to_move == dst.len() { Ok(()) }
 			else { Err(to_move) }
 	}
 	
+	/// Consumes the last element and returns it
+	///
+	/// Returns either __`Ok(element)`__ if there was an element to consume or __`Err(())`__
+	/// otherwise
+	fn pop_back(&mut self) -> Result<T, ()> {
+		match self.is_empty() {
+			true => Err(()),
+			false => {
+				let element = self.backing.pop().unwrap();
+				self.compact_opportunistic();
+				self.auto_shrink();
+				Ok(element)
+			}
+		}
+	}
+	/// Consumes the last `n` elements and returns them in their original order
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to consume
+	///
+	/// Returns either __`Ok(elements)`__ if there were `n` elements avaliable to consume or
+	/// __`Err(elements)`__ if less elements were available
+	fn pop_back_n(&mut self, n: usize) -> Result<Vec<T>, Vec<T>> {
+		// Split the elements off
+		let to_consume = min(self.len(), n);
+		let split_at = self.backing.len() - to_consume;
+		let elements = self.backing.split_off(split_at);
+		
+		// Shrink and return result
+		self.compact_opportunistic();
+		self.auto_shrink();
+		if to_consume == n { Ok(elements) }
+			else { Err(elements) }
+	}
+	
 	/// Discards the first `n` elements
 	///
 	/// Parameters:
@@ -222,14 +1472,75 @@ impl<T> ReadableSliceQueue<T> for SliceQueue<T> {
 	fn drop_n(&mut self, n: usize) -> Result<(), usize> {
 		// Drop `n` elements and copy the remaining elements to the front
 		let to_drop = min(self.len(), n);
-		mem::drop_n(&mut self.backing, to_drop);
+		mem::drop_n(&mut self.backing, &mut self.head, to_drop);
 		
 		// Shrink and return result
+		self.compact_opportunistic();
 		self.auto_shrink();
 		if to_drop == n { Ok(()) }
 			else { Err(to_drop) }
 	}
 }
+#[cfg(feature = "std")]
+impl SliceQueue<u8> {
+	/// Writes all stored bytes to `writer` and consumes the bytes written
+	///
+	/// Short writes are retried until all bytes are written. If an error occurs, only the bytes
+	/// that have been written successfully are consumed.
+	///
+	/// Parameters:
+	///  - `writer`: The writer to write the bytes to
+	///
+	/// Returns either __the amount of bytes written__ or __the error `writer` returned__ (or an
+	/// `ErrorKind::WriteZero`-error if `writer` does not accept any more bytes or an
+	/// `ErrorKind::InvalidData`-error if `writer` claims to have written more bytes than passed)
+	pub fn drain_to<W: Write>(&mut self, writer: &mut W) -> IoResult<usize> {
+		let mut written = 0;
+		while !self.is_empty() {
+			match writer.write(self.as_slice()) {
+				Ok(0) => return Err(IoError::new(ErrorKind::WriteZero, "failed to write the stored bytes")),
+				Ok(n) if n > self.len() => return Err(IoError::new(ErrorKind::InvalidData, "writer reported an invalid amount of written bytes")),
+				Ok(n) => {
+					let _ = self.drop_n(n);
+					written += n;
+				},
+				Err(ref e) if e.kind() == ErrorKind::Interrupted => (),
+				Err(e) => return Err(e)
+			}
+		}
+		Ok(written)
+	}
+	/// Reads up to `max` bytes from `reader` directly into `self` without an intermediate buffer
+	///
+	/// _Info: `max` is clamped to `self.remaining()` and to either the spare capacity or 8 KiB
+	/// (whichever is larger); so a single call never allocates more than necessary, even if `max`
+	/// is `usize::MAX`._
+	///
+	/// Parameters:
+	///  - `reader`: The reader to read the bytes from
+	///  - `max`: The maximum amount of bytes to read
+	///
+	/// Returns either __the amount of bytes read__ (`0` on EOF or if `max` is `0`) or __the error
+	/// `reader` returned__ (or an `ErrorKind::Other`-error wrapping a
+	/// `SliceQueueError::LimitExceeded` if the limit has already been reached)
+	pub fn fill_from<R: Read>(&mut self, reader: &mut R, max: usize) -> IoResult<usize> {
+		if max > 0 && self.is_full() {
+			let error = SliceQueueError::LimitExceeded{ limit: self.limit, requested: self.len().saturating_add(max) };
+			return Err(IoError::other(error))
+		}
+		
+		// Clamp the amount of bytes to read to avoid huge allocations
+		let spare = self.backing.capacity() - self.backing.len();
+		let to_read = min(min(max, self.remaining()), spare.max(FILL_CHUNK_SIZE));
+		loop {
+			match self.push_in_place(to_read, |buf: &mut[u8]| reader.read(buf)) {
+				Err(ref e) if e.kind() == ErrorKind::Interrupted => (),
+				result => return result
+			}
+		}
+	}
+}
+#[cfg(feature = "std")]
 impl Read for SliceQueue<u8> {
 	/// Pull some bytes from this source into the specified buffer, returning how many bytes were
 	/// read.
@@ -258,12 +1569,20 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	///
 	/// Returns __the amount of space remaining in `self` until `self.limit` is reached__
 	fn remaining(&self) -> usize {
-		self.limit.checked_sub(self.len()).unwrap_or_default()
+		self.limit.saturating_sub(self.len())
+	}
+	/// Checks if `self.limit` has been reached
+	///
+	/// Returns _`true`_ if no more elements can be appended or _`false`_ otherwise
+	fn is_full(&self) -> bool {
+		self.len() >= self.limit
 	}
 	
 	/// Reserves an additional amount of memory to append `n` elements without reallocating
 	///
-	/// Does nothing if `self.reserved` is greater or equal `n`
+	/// Consumed elements are compacted first so that their space can be reused; afterwards nothing
+	/// is allocated if `self.reserved` is greater or equal `n`. The reservation is capped at
+	/// `self.remaining` so that no memory is allocated for elements beyond `self.limit`.
 	///
 	/// Parameters:
 	///  - `n`: The amount of elements that we should be able to append without reallocating
@@ -272,17 +1591,35 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	/// elements reserved_ if `n` was greater than `self.remaining`.
 	fn reserve_n(&mut self, n: usize) -> Result<(), usize> {
 		// Reserve elements
-		let to_reserve = min(self.limit.checked_sub(self.backing.capacity()).unwrap_or_default(), n);
+		let to_reserve = min(self.remaining(), n);
+		mem::compact(&mut self.backing, &mut self.head);
 		self.backing.reserve_exact(to_reserve);
 		
 		if to_reserve == n { Ok(()) }
 			else { Err(to_reserve) }
 	}
+	/// Reserves an additional amount of memory to append `n` elements without reallocating like
+	/// `self.reserve_n` but returns an error instead of aborting if the allocation fails
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements that we should be able to append without reallocating
+	///
+	/// Returns either __`Ok(result)`__ with the result `self.reserve_n` would have returned or
+	/// __`Err(error)`__ if the allocation failed
+	fn try_reserve_n(&mut self, n: usize) -> Result<Result<(), usize>, TryReserveError> {
+		// Reserve elements
+		let to_reserve = min(self.remaining(), n);
+		mem::compact(&mut self.backing, &mut self.head);
+		self.backing.try_reserve_exact(to_reserve)?;
+		
+		if to_reserve == n { Ok(Ok(())) }
+			else { Ok(Err(to_reserve)) }
+	}
 	/// The amount of elements that can be appended with out reallocating
 	///
 	/// Returns __the amount of elements that can be appended with out reallocating__
 	fn reserved(&self) -> usize {
-		self.backing.capacity() - self.len()
+		self.backing.capacity() - self.backing.len()
 	}
 	
 	/// Appends `element` at the end
@@ -293,8 +1630,11 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	/// Returns either __`Ok(())`__ if the element was pushed successfully or __`Err(element)`__ if
 	/// `element` was not appended because `self.limit` would have been exceeded
 	fn push(&mut self, element: T) -> Result<(), T> {
-		if self.remaining() >= 1 { Ok(self.backing.push(element)) }
-			else { Err(element) }
+		if !self.has_capacity(1) { return Err(element) }
+		
+		self.make_room(1);
+		self.backing.push(element);
+		Ok(())
 	}
 	/// Appends `n` at the end
 	///
@@ -305,10 +1645,12 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	/// if `n` was only appended partially because `self.limit` would have been exceeded
 	fn push_n(&mut self, mut n: Vec<T>) -> Result<(), Vec<T>> {
 		if self.remaining() >= n.len() {
+			self.make_room(n.len());
 			self.backing.append(&mut n);
 			Ok(())
 		} else {
 			let remaining = n.split_off(self.remaining());
+			self.make_room(n.len());
 			self.backing.append(&mut n);
 			Err(remaining)
 		}
@@ -323,11 +1665,46 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	/// would have been exceeded
 	fn push_from(&mut self, src: &[T]) -> Result<(), usize> where T: Clone {
 		let to_append = min(self.remaining(), src.len());
+		self.make_room(to_append);
 		self.backing.extend_from_slice(&src[..to_append]);
 		
 		if to_append == src.len() { Ok(()) }
 			else { Err(to_append) }
 	}
+	/// Copies and appends the elements in `src` at the end like `self.push_from` but uses a fast
+	/// memory copy for `Copy`-types (if the `unsafe_fast_code` feature is enabled)
+	///
+	/// Parameters:
+	///  - `src`: A slice containing the elements to copy and append
+	///
+	/// Returns either __`Ok(())`__ if `src` was appended completely or
+	/// __`Err(appended_element_count)`__ if `src` was only appended partially because `self.limit`
+	/// would have been exceeded
+	fn push_copy_from(&mut self, src: &[T]) -> Result<(), usize> where T: Copy {
+		let to_append = min(self.remaining(), src.len());
+		self.make_room(to_append);
+		mem::copy_from(&mut self.backing, &src[..to_append]);
+		
+		if to_append == src.len() { Ok(()) }
+			else { Err(to_append) }
+	}
+	/// Appends `n` default elements at the end (e.g. to pad a frame with zero bytes)
+	///
+	/// Parameters:
+	///  - `n`: The amount of default elements to append
+	///
+	/// Returns either __`Ok(())`__ if `n` elements were appended or
+	/// __`Err(appended_element_count)`__ if less elements were appended because `self.limit`
+	/// would have been exceeded
+	fn push_default(&mut self, n: usize) -> Result<(), usize> where T: Default {
+		let to_append = min(self.remaining(), n);
+		self.make_room(to_append);
+		let new_len = self.backing.len() + to_append;
+		self.backing.resize_with(new_len, T::default);
+		
+		if to_append == n { Ok(()) }
+			else { Err(to_append) }
+	}
 	/// Calls `push_fn` to push up to `n` elements in place
 	///
 	/// __Warning: This function panics if `self.limit` is exceeded__
@@ -349,27 +1726,47 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 	/// ```
 	/// # extern crate slice_queue;
 	/// # use slice_queue::*;
-	///	let mut slice_queue = SliceQueue::new();
+	/// let mut slice_queue = SliceQueue::new();
 	///
 	/// // Successful push
-	///	slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
-	/// 	(0..4).for_each(|i| buffer[i] = i);
-	/// 	Ok(4)
+	/// slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
+	///     (0..4).for_each(|i| buffer[i] = i);
+	///     Ok(4)
 	/// });
 	/// assert_eq!(slice_queue.len(), 4);
 	/// (0..4).for_each(|i| assert_eq!(slice_queue[i], i));
 	///
 	/// // Failed push
 	/// slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
-	/// 	(0..4).for_each(|i| buffer[i] = i + 7);
-	/// 	Err(())
+	///     (0..4).for_each(|i| buffer[i] = i + 7);
+	///     Err(())
 	/// });
 	/// assert_eq!(slice_queue.len(), 4);
 	/// (0..4).for_each(|i| assert_eq!(slice_queue[i], i));
-	///	```
-	fn push_in_place<E>(&mut self, n: usize, mut push_fn: impl FnMut(&mut[T]) -> Result<usize, E>) -> Result<usize, E> where T: Default {
-		assert!(self.limit >= self.len() + n, "`self.len() + n` is larger than `self.limit`");
-		let old_len = self.len();
+	/// ```
+	fn push_in_place<E>(&mut self, n: usize, push_fn: impl FnMut(&mut[T]) -> Result<usize, E>) -> Result<usize, E> where T: Default {
+		self.check_capacity(n);
+		match self.try_push_in_place(n, push_fn) {
+			Ok(pushed) => pushed,
+			Err(_) => unreachable!("`self.limit` has already been checked")
+		}
+	}
+	/// Calls `push_fn` to push up to `n` elements in place like `self.push_in_place` but returns
+	/// an error instead of panicking if `self.limit` would be exceeded
+	///
+	/// If `self.limit` would be exceeded, nothing is inserted and `push_fn` is not called.
+	///
+	/// Parameters:
+	///  - `n`: The amount of bytes to reserve
+	///  - `push_fn`: The pushing callback
+	///
+	/// Returns either _`Ok(result)`_ with the result of `self.push_in_place` or _`Err(remaining)`_
+	/// with the amount of elements that could have been pushed if `self.len() + n` is larger
+	/// than `self.limit`
+	fn try_push_in_place<E>(&mut self, n: usize, mut push_fn: impl FnMut(&mut[T]) -> Result<usize, E>) -> Result<Result<usize, E>, usize> where T: Default {
+		if !self.has_capacity(n) { return Err(self.remaining()) }
+		self.make_room(n);
+		let old_len = self.backing.len();
 		
 		// Append `n` default elements
 		self.backing.reserve(n);
@@ -384,9 +1781,10 @@ impl<T> WriteableSliceQueue<T> for SliceQueue<T> {
 		});
 		self.shrink_opportunistic();
 		
-		pushed
+		Ok(pushed)
 	}
 }
+#[cfg(feature = "std")]
 impl Write for SliceQueue<u8> {
 	/// Write a buffer into this object, returning how many bytes were written.
     ///
@@ -407,6 +1805,24 @@ impl Write for SliceQueue<u8> {
 			Err(pushed) => Ok(pushed)
 		}
 	}
+	/// Like `write`, except that it writes from a slice of buffers
+	///
+	/// The capacity for all buffers is reserved at once; the buffers are appended until the limit
+	/// is reached.
+	///
+	/// __This call never fails; the result is only used for trait-compatibility__
+	fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> IoResult<usize> {
+		// Reserve the space for all buffers
+		let len = self.len();
+		let total = bufs.iter().fold(0usize, |total, buf| total.saturating_add(buf.len()));
+		let _ = self.reserve_n(total);
+		
+		// Append the buffers until the limit is reached
+		for buf in bufs {
+			if self.push_from(buf).is_err() { break }
+		}
+		Ok(self.len() - len)
+	}
 	/// __This call does nothing (and thus never fails); it is only provided for
 	/// trait-compatibility__
 	fn flush(&mut self) -> IoResult<()> {
@@ -417,46 +1833,216 @@ impl Write for SliceQueue<u8> {
 
 impl<T: Debug> Debug for SliceQueue<T> {
 	fn fmt(&self, f: &mut Formatter) -> FmtResult {
-		f.debug_struct("SliceQueue").field("backing", &self.backing).finish()
+		f.debug_struct("SliceQueue")
+			.field("len", &self.len())
+			.field("limit", &self.limit)
+			.field("elements", &self.as_slice())
+			.finish()
+	}
+}
+
+
+impl<T> PartialEq for SliceQueue<T> where T: PartialEq {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+impl<T> PartialEq<[T]> for SliceQueue<T> where T: PartialEq {
+	fn eq(&self, other: &[T]) -> bool {
+		self.as_slice() == other
+	}
+}
+impl<T, const N: usize> PartialEq<[T; N]> for SliceQueue<T> where T: PartialEq {
+	fn eq(&self, other: &[T; N]) -> bool {
+		self.as_slice() == other
+	}
+}
+impl<T> PartialEq<Vec<T>> for SliceQueue<T> where T: PartialEq {
+	fn eq(&self, other: &Vec<T>) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+impl<T> Eq for SliceQueue<T> where T: Eq {}
+
+
+impl<T> PartialOrd for SliceQueue<T> where T: PartialOrd {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.as_slice().partial_cmp(other.as_slice())
+	}
+}
+impl<T> Ord for SliceQueue<T> where T: Ord {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+
+impl<T> Hash for SliceQueue<T> where T: Hash {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state)
 	}
 }
 
 
-impl<'a, T> From<&'a[T]> for SliceQueue<T> where T: Clone {
+impl<T> From<&[T]> for SliceQueue<T> where T: Clone {
 	fn from(slice: &[T]) -> Self {
-		SliceQueue{ backing: slice.to_vec(), limit: usize::MAX, auto_shrink_mode: Default::default() }
+		SliceQueue::from_backing(slice.to_vec(), usize::MAX)
+	}
+}
+impl<T, const N: usize> From<&[T; N]> for SliceQueue<T> where T: Clone {
+	fn from(array: &[T; N]) -> Self {
+		SliceQueue::from(array.as_ref())
 	}
 }
 impl<T> From<Vec<T>> for SliceQueue<T> {
 	fn from(vec: Vec<T>) -> Self {
-		SliceQueue{ backing: vec, limit: usize::MAX, auto_shrink_mode: Default::default() }
+		SliceQueue::from_backing(vec, usize::MAX)
+	}
+}
+impl<T> From<SliceQueue<T>> for Vec<T> {
+	fn from(mut slice_queue: SliceQueue<T>) -> Self {
+		mem::compact(&mut slice_queue.backing, &mut slice_queue.head);
+		take(&mut slice_queue.backing)
+	}
+}
+impl<T> From<VecDeque<T>> for SliceQueue<T> {
+	fn from(vec_deque: VecDeque<T>) -> Self {
+		SliceQueue::from(Vec::from(vec_deque))
+	}
+}
+impl<T> From<SliceQueue<T>> for VecDeque<T> {
+	fn from(slice_queue: SliceQueue<T>) -> Self {
+		VecDeque::from(Vec::from(slice_queue))
+	}
+}
+
+
+impl<T> AsRef<[T]> for SliceQueue<T> {
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+impl<T> AsMut<[T]> for SliceQueue<T> {
+	fn as_mut(&mut self) -> &mut[T] {
+		self.as_mut_slice()
+	}
+}
+
+
+impl<T> Borrow<[T]> for SliceQueue<T> {
+	fn borrow(&self) -> &[T] {
+		self.as_slice()
 	}
 }
-impl<T> Into<Vec<T>> for SliceQueue<T> {
-	fn into(self) -> Vec<T> {
-		self.backing
+impl<T> BorrowMut<[T]> for SliceQueue<T> {
+	fn borrow_mut(&mut self) -> &mut[T] {
+		self.as_mut_slice()
+	}
+}
+
+
+impl<T> IntoIterator for SliceQueue<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+	fn into_iter(self) -> Self::IntoIter {
+		Vec::from(self).into_iter()
+	}
+}
+impl<'a, T> IntoIterator for &'a SliceQueue<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+impl<'a, T> IntoIterator for &'a mut SliceQueue<T> {
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+
+impl<T> Extend<T> for SliceQueue<T> {
+	/// Appends all elements yielded by `iter` at the end
+	///
+	/// __Warning: This function panics if `self.limit` is exceeded__
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		// Reserve the space for the elements expected
+		let iter = iter.into_iter();
+		let expected = min(self.remaining(), iter.size_hint().0);
+		self.make_room(expected);
+		self.backing.reserve(expected);
+		
+		// Push the elements
+		iter.for_each(|element| {
+			self.check_capacity(1);
+			self.make_room(1);
+			self.backing.push(element)
+		})
+	}
+}
+impl<'a, T> Extend<&'a T> for SliceQueue<T> where T: Copy + 'a {
+	/// Appends copies of all elements yielded by `iter` at the end
+	///
+	/// __Warning: This function panics if `self.limit` is exceeded__
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+		self.extend(iter.into_iter().cloned())
+	}
+}
+impl<T> FromIterator<T> for SliceQueue<T> {
+	/// Collects all elements yielded by `iter` into a new `SliceQueue`
+	///
+	/// _Info: The new `SliceQueue` uses the default limit `usize::MAX`; use
+	/// `SliceQueue::from_iter_with_limit` if you want to enforce a limit._
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		SliceQueue::from(iter.into_iter().collect::<Vec<T>>())
+	}
+}
+
+
+impl<T> Default for SliceQueue<T> {
+	fn default() -> Self {
+		SliceQueue::new()
+	}
+}
+
+
+impl<T> Drop for SliceQueue<T> {
+	fn drop(&mut self) {
+		mem::clear(&mut self.backing, &mut self.head)
 	}
 }
 
 
 impl<T> Clone for SliceQueue<T> where T: Clone {
 	fn clone(&self) -> Self {
-		SliceQueue{ backing: self.backing.clone(), limit: self.limit, auto_shrink_mode: Default::default() }
+		let mut cloned = SliceQueue::from_backing(self.as_slice().to_vec(), self.limit);
+		cloned.copy_settings_from(self);
+		cloned
+	}
+	/// Clones the elements and the settings of `source` into `self` and reuses the allocated
+	/// capacity of `self`
+	fn clone_from(&mut self, source: &Self) {
+		mem::compact(&mut self.backing, &mut self.head);
+		source.as_slice().clone_into(&mut self.backing);
+		self.copy_settings_from(source);
 	}
 }
 
 
 macro_rules! index_impl {
     ($range_ty:path) => {
-    	impl<T> ::std::ops::Index<$range_ty> for SliceQueue<T> {
+    	impl<T> ::core::ops::Index<$range_ty> for SliceQueue<T> {
 			type Output = [T];
 			fn index(&self, range: $range_ty) -> &[T] {
-				&self.backing[range]
+				&self.as_slice()[range]
 			}
 		}
-		impl<T> ::std::ops::IndexMut<$range_ty> for SliceQueue<T> {
+		impl<T> ::core::ops::IndexMut<$range_ty> for SliceQueue<T> {
 			fn index_mut(&mut self, range: $range_ty) -> &mut[T] {
-				&mut self.backing[range]
+				&mut self.as_mut_slice()[range]
 			}
 		}
     };
@@ -472,28 +2058,28 @@ index_impl!(RangeToInclusive<usize>);
 impl<T> Index<usize> for SliceQueue<T> {
 	type Output = T;
 	fn index(&self, i: usize) -> &T {
-		&self.backing[i]
+		&self.as_slice()[i]
 	}
 }
 impl<T> IndexMut<usize> for SliceQueue<T> {
 	fn index_mut(&mut self, i: usize) -> &mut T {
-		&mut self.backing[i]
+		&mut self.as_mut_slice()[i]
 	}
 }
 
 
 #[cfg(feature = "deref")]
-use std::ops::{ Deref, DerefMut };
+use core::ops::{ Deref, DerefMut };
 #[cfg(feature = "deref")]
 impl<T> Deref for SliceQueue<T> {
 	type Target = <Vec<T> as Deref>::Target;
 	fn deref(&self) -> &Self::Target {
-		self.backing.deref()
+		self.as_slice()
 	}
 }
 #[cfg(feature = "deref")]
 impl<T> DerefMut for SliceQueue<T> {
 	fn deref_mut(&mut self) -> &mut Self::Target {
-		self.backing.deref_mut()
+		self.as_mut_slice()
 	}
 }
\ No newline at end of file
@@ -0,0 +1,61 @@
+use core::cmp::min;
+
+
+/// A non-consuming cursor over the elements of a `SliceQueue` with its own position
+///
+/// This allows you to parse speculatively and to consume the elements from the `SliceQueue` (e.g.
+/// using `drop_n(reader.position())`) only once a complete frame was recognized.
+#[derive(Copy, Clone, Debug)]
+pub struct SliceQueueReader<'a, T> {
+	elements: &'a [T],
+	position: usize
+}
+impl<'a, T> SliceQueueReader<'a, T> {
+	/// Creates a new `SliceQueueReader` over `elements`
+	///
+	/// Parameters:
+	///  - `elements`: The elements to read
+	///
+	/// Returns __the new `SliceQueueReader`__
+	pub(crate) fn new(elements: &'a [T]) -> Self {
+		SliceQueueReader{ elements, position: 0 }
+	}
+	
+	
+	/// The amount of elements read
+	///
+	/// Returns __the amount of elements read from the front__
+	pub fn position(&self) -> usize {
+		self.position
+	}
+	/// The amount of elements remaining
+	///
+	/// Returns __the amount of elements that have not been read yet__
+	pub fn remaining(&self) -> usize {
+		self.elements.len() - self.position
+	}
+	
+	
+	/// Take a look at the next element __without__ reading it
+	///
+	/// Returns either _`Some(element_ref)`_ if there is a next element or _`None`_ if all
+	/// elements have been read
+	pub fn peek(&self) -> Option<&'a T> {
+		self.elements.get(self.position)
+	}
+	/// Reads the next `n` elements
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to read
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to read or
+	/// __`Err(element_refs)`__ if less elements were available
+	pub fn read_n(&mut self, n: usize) -> Result<&'a [T], &'a [T]> {
+		let to_read = min(self.remaining(), n);
+		let elements = &self.elements[self.position..self.position + to_read];
+		self.position += to_read;
+		
+		if to_read == n { Ok(elements) }
+			else { Err(elements) }
+	}
+}
@@ -0,0 +1,20 @@
+use super::SliceQueue;
+use alloc::vec::Vec;
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+
+
+impl<T> Serialize for SliceQueue<T> where T: Serialize {
+	/// Serializes the stored elements as sequence (the limit and the capacity are not serialized)
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.as_slice())
+	}
+}
+impl<'de, T> Deserialize<'de> for SliceQueue<T> where T: Deserialize<'de> {
+	/// Deserializes a sequence into a new `SliceQueue` with the default limit and a capacity that
+	/// matches the amount of deserialized elements
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let mut slice_queue = SliceQueue::from(Vec::deserialize(deserializer)?);
+		slice_queue.shrink_to_fit();
+		Ok(slice_queue)
+	}
+}
@@ -1,3 +1,7 @@
+use core::cmp::min;
+use alloc::{ vec::Vec, collections::TryReserveError };
+
+
 pub trait ReadableSliceQueue<T> {
 	/// The amount of elements stored
 	///
@@ -12,6 +16,26 @@ pub trait ReadableSliceQueue<T> {
 	///
 	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ otherwise
 	fn peek(&self) -> Option<&T>;
+	/// Take a mutable look at the first element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a first element or _`None`_ otherwise
+	fn peek_mut(&mut self) -> Option<&mut T> {
+		self.peek_n_mut(1).ok()?.first_mut()
+	}
+	/// Take a look at the last element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ otherwise
+	fn peek_back(&self) -> Option<&T> {
+		match self.peek_n(self.len()) {
+			Ok(elements) | Err(elements) => elements.last()
+		}
+	}
+	/// Take a mutable look at the last element __without__ consuming it
+	///
+	/// Returns either _`Some(element_ref)`_ if we have a last element or _`None`_ otherwise
+	fn peek_back_mut(&mut self) -> Option<&mut T> {
+		self.peek_back_n_mut(1).ok()?.last_mut()
+	}
 	/// Take a look at the first `n` elements __without__ consuming them
 	///
 	/// Parameters:
@@ -20,11 +44,66 @@ pub trait ReadableSliceQueue<T> {
 	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
 	/// __`Err(element_refs)`__ if less elements were available
 	fn peek_n(&self, n: usize) -> Result<&[T], &[T]>;
+	/// Take a mutable look at the first `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_n_mut(&mut self, n: usize) -> Result<&mut[T], &mut[T]>;
+	/// Take a look at the last `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_back_n(&self, n: usize) -> Result<&[T], &[T]> {
+		let elements = match self.peek_n(self.len()) {
+			Ok(elements) | Err(elements) => elements
+		};
+		if n <= elements.len() { Ok(&elements[elements.len() - n..]) }
+			else { Err(elements) }
+	}
+	/// Take a mutable look at the last `n` elements __without__ consuming them
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to peek at
+	///
+	/// Returns either __`Ok(element_refs)`__ if there were `n` elements avaliable to peek at or
+	/// __`Err(element_refs)`__ if less elements were available
+	fn peek_back_n_mut(&mut self, n: usize) -> Result<&mut[T], &mut[T]> {
+		let len = self.len();
+		let elements = match self.peek_n_mut(len) {
+			Ok(elements) | Err(elements) => elements
+		};
+		let available = elements.len();
+		if n <= available { Ok(&mut elements[available - n..]) }
+			else { Err(elements) }
+	}
+	/// Copies the first `dst.len()` elements into `dst` __without__ consuming them
+	///
+	/// _Info: This call never panics; if less elements are available, only the first
+	/// `self.len()` elements in `dst` are replaced and the remaining elements are left untouched._
+	///
+	/// Parameters:
+	///  - `dst`: The target slice to copy the elements into
+	///
+	/// Returns __the amount of elements copied__
+	fn copy_into(&self, dst: &mut[T]) -> usize where T: Copy {
+		let elements = match self.peek_n(dst.len()) {
+			Ok(elements) | Err(elements) => elements
+		};
+		dst[..elements.len()].copy_from_slice(elements);
+		elements.len()
+	}
 	
 	/// Consumes the first element and returns it
 	///
 	/// Returns either __`Ok(element)`__ if there was an element to consume or __`Err(())`__
 	/// otherwise
+	#[allow(clippy::result_unit_err)]
 	fn pop(&mut self) -> Result<T, ()>;
 	/// Consumes the first `n` elements and returns them
 	///
@@ -34,8 +113,40 @@ pub trait ReadableSliceQueue<T> {
 	/// Returns either __`Ok(elements)`__ if there were `n` elements avaliable to consume or
 	/// __`Err(elements)`__ if less elements were available
 	fn pop_n(&mut self, n: usize) -> Result<Vec<T>, Vec<T>>;
+	/// Consumes up to `n` elements and returns them (all elements if less than `n` are available)
+	///
+	/// Parameters:
+	///  - `n`: The maximum amount of elements to consume
+	///
+	/// Returns __the consumed elements__
+	fn pop_n_max(&mut self, n: usize) -> Vec<T> {
+		match self.pop_n(n) {
+			Ok(elements) | Err(elements) => elements
+		}
+	}
+	/// Consumes the first `n` elements and appends them to `dst`
+	///
+	/// This allows you to reuse `dst` instead of allocating a new `Vec<T>` for each call.
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to consume
+	///  - `dst`: The vector to append the elements to
+	///
+	/// Returns either _`true`_ if `n` elements were appended or _`false`_ if less elements were
+	/// available (in this case nothing is consumed)
+	fn pop_n_append_to(&mut self, n: usize, dst: &mut Vec<T>) -> bool {
+		if n > self.len() { return false }
+		match self.pop_n(n) {
+			Ok(mut elements) => { dst.append(&mut elements); true },
+			Err(_) => unreachable!("`self.len()` has already been checked")
+		}
+	}
 	/// Consumes the first `dst.len()` and moves them into `dst`
 	///
+	/// _Info: This call never panics; if less elements are available, only the first
+	/// `element_count` elements in `dst` are replaced and the remaining elements are left
+	/// untouched._
+	///
 	/// Parameters:
 	///  - `dst`: The target to move the elements into
 	///
@@ -43,6 +154,27 @@ pub trait ReadableSliceQueue<T> {
 	/// only `element_count` elements were moved
 	fn pop_into(&mut self, dst: &mut[T]) -> Result<(), usize>;
 	
+	/// Consumes the last element and returns it
+	///
+	/// Returns either __`Ok(element)`__ if there was an element to consume or __`Err(())`__
+	/// otherwise
+	#[allow(clippy::result_unit_err)]
+	fn pop_back(&mut self) -> Result<T, ()>;
+	/// Consumes the last `n` elements and returns them in their original order
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements to consume
+	///
+	/// Returns either __`Ok(elements)`__ if there were `n` elements avaliable to consume or
+	/// __`Err(elements)`__ if less elements were available
+	fn pop_back_n(&mut self, n: usize) -> Result<Vec<T>, Vec<T>> {
+		let mut elements: Vec<T> = (0..n).map_while(|_| self.pop_back().ok()).collect();
+		elements.reverse();
+		
+		if elements.len() == n { Ok(elements) }
+			else { Err(elements) }
+	}
+	
 	/// Discards the first `n` elements
 	///
 	/// Parameters:
@@ -59,10 +191,17 @@ pub trait WriteableSliceQueue<T> {
 	///
 	/// Returns __the amount of space remaining in `self` until `self.limit` is reached__
 	fn remaining(&self) -> usize;
+	/// Checks if `self.limit` has been reached
+	///
+	/// Returns _`true`_ if no more elements can be appended or _`false`_ otherwise
+	fn is_full(&self) -> bool {
+		self.remaining() == 0
+	}
 	
 	/// Reserves an additional amount of memory to append `n` elements without reallocating
 	///
-	/// Does nothing if `self.reserved` is greater or equal `n`
+	/// Does nothing if `self.reserved` is greater or equal `n`. The reservation is capped at
+	/// `self.remaining` so that no memory is allocated for elements beyond `self.limit`.
 	///
 	/// Parameters:
 	///  - `n`: The amount of elements that we should be able to append without reallocating
@@ -70,6 +209,20 @@ pub trait WriteableSliceQueue<T> {
 	/// Returns either _nothing_ if the space for `n` elements could be reserved or _the amount of
 	/// elements reserved_ if `n` was greater than `self.remaining`.
 	fn reserve_n(&mut self, n: usize) -> Result<(), usize>;
+	/// Reserves an additional amount of memory to append `n` elements without reallocating like
+	/// `self.reserve_n` but returns an error instead of aborting if the allocation fails
+	///
+	/// Parameters:
+	///  - `n`: The amount of elements that we should be able to append without reallocating
+	///
+	/// _Info: The default implementation delegates to `self.reserve_n` and thus never returns an
+	/// error; implementors should override it if they can report allocation failures._
+	///
+	/// Returns either __`Ok(result)`__ with the result `self.reserve_n` would have returned or
+	/// __`Err(error)`__ if the allocation failed
+	fn try_reserve_n(&mut self, n: usize) -> Result<Result<(), usize>, TryReserveError> {
+		Ok(self.reserve_n(n))
+	}
 	/// The amount of elements that can be appended with out reallocating
 	///
 	/// Returns __the amount of elements that can be appended with out reallocating__
@@ -100,6 +253,35 @@ pub trait WriteableSliceQueue<T> {
 	/// __`Err(remaining_element_count)`__ if `src` was only appended partially because `self.limit`
 	/// would have been exceeded
 	fn push_from(&mut self, src: &[T]) -> Result<(), usize> where T: Clone;
+	/// Copies and appends the elements in `src` at the end like `self.push_from` but uses a fast
+	/// memory copy for `Copy`-types (if the `unsafe_fast_code` feature is enabled)
+	///
+	/// Parameters:
+	///  - `src`: A slice containing the elements to copy and append
+	///
+	/// Returns either __`Ok(())`__ if `src` was appended completely or
+	/// __`Err(appended_element_count)`__ if `src` was only appended partially because `self.limit`
+	/// would have been exceeded
+	fn push_copy_from(&mut self, src: &[T]) -> Result<(), usize> where T: Copy {
+		self.push_from(src)
+	}
+	/// Appends `n` default elements at the end (e.g. to pad a frame with zero bytes)
+	///
+	/// Parameters:
+	///  - `n`: The amount of default elements to append
+	///
+	/// Returns either __`Ok(())`__ if `n` elements were appended or
+	/// __`Err(appended_element_count)`__ if less elements were appended because `self.limit`
+	/// would have been exceeded
+	fn push_default(&mut self, n: usize) -> Result<(), usize> where T: Default {
+		let to_push = min(self.remaining(), n);
+		let elements = (0..to_push).map(|_| T::default()).collect();
+		match self.push_n(elements) {
+			Ok(()) if to_push == n => Ok(()),
+			Ok(()) => Err(to_push),
+			Err(elements) => Err(to_push - elements.len())
+		}
+	}
 	/// Calls `push_fn` to push up to `n` elements in place
 	///
 	/// __Warning: This function panics if `self.limit` is exceeded__
@@ -121,23 +303,39 @@ pub trait WriteableSliceQueue<T> {
 	/// ```
 	/// # extern crate slice_queue;
 	/// # use slice_queue::*;
-	///	let mut slice_queue = SliceQueue::new();
+	/// let mut slice_queue = SliceQueue::new();
 	///
 	/// // Successful push
-	///	slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
-	/// 	(0..4).for_each(|i| buffer[i] = i);
-	/// 	Ok(4)
+	/// slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
+	///     (0..4).for_each(|i| buffer[i] = i);
+	///     Ok(4)
 	/// });
 	/// assert_eq!(slice_queue.len(), 4);
 	/// (0..4).for_each(|i| assert_eq!(slice_queue[i], i));
 	///
 	/// // Failed push
 	/// slice_queue.push_in_place(7, |buffer: &mut[usize]| -> Result<usize, ()> {
-	/// 	(0..4).for_each(|i| buffer[i] = i + 7);
-	/// 	Err(())
+	///     (0..4).for_each(|i| buffer[i] = i + 7);
+	///     Err(())
 	/// });
 	/// assert_eq!(slice_queue.len(), 4);
 	/// (0..4).for_each(|i| assert_eq!(slice_queue[i], i));
-	///	```
+	/// ```
 	fn push_in_place<E>(&mut self, n: usize, push_fn: impl FnMut(&mut[T]) -> Result<usize, E>) -> Result<usize, E> where T: Default;
+	/// Calls `push_fn` to push up to `n` elements in place like `self.push_in_place` but returns
+	/// an error instead of panicking if `self.limit` would be exceeded
+	///
+	/// If `self.limit` would be exceeded, nothing is inserted and `push_fn` is not called.
+	///
+	/// Parameters:
+	///  - `n`: The amount of bytes to reserve
+	///  - `push_fn`: The pushing callback
+	///
+	/// Returns either _`Ok(result)`_ with the result of `self.push_in_place` or _`Err(remaining)`_
+	/// with the amount of elements that could have been pushed if `self.len() + n` is larger
+	/// than `self.limit`
+	fn try_push_in_place<E>(&mut self, n: usize, push_fn: impl FnMut(&mut[T]) -> Result<usize, E>) -> Result<Result<usize, E>, usize> where T: Default {
+		if n <= self.remaining() { Ok(self.push_in_place(n, push_fn)) }
+			else { Err(self.remaining()) }
+	}
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use super::SliceQueue;
+use zeroize::Zeroize;
+
+
+impl<T> Zeroize for SliceQueue<T> where T: Zeroize {
+	/// Zeroes the stored elements and the entire allocated capacity and leaves `self` empty
+	///
+	/// The elements are zeroed before they are dropped; the allocated capacity (including the space
+	/// of already consumed elements) is kept.
+	///
+	/// _Info: Like `Vec`'s implementation, this is "best effort": memory that was released by a
+	/// previous reallocation or shrink cannot be zeroed. Use `AutoShrinkMode::Disabled` and
+	/// preallocate the required capacity to avoid leaving copies behind._
+	fn zeroize(&mut self) {
+		self.compacted_backing_mut().zeroize()
+	}
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "bytes")]
+extern crate slice_queue;
+extern crate bytes;
+use { slice_queue::*, bytes::{ Buf, BufMut } };
+
+
+#[test]
+fn test_buf() {
+	let mut slice_queue = SliceQueue::new();
+	slice_queue.push_from(b"\x00\x07Testolope").unwrap();
+	
+	assert_eq!(Buf::remaining(&slice_queue), 11);
+	assert_eq!(slice_queue.get_u16(), 7);
+	assert_eq!(slice_queue.chunk(), b"Testolope");
+	
+	slice_queue.advance(4);
+	assert_eq!(slice_queue.chunk(), b"olope");
+	assert_eq!(slice_queue.copy_to_bytes(5).as_ref(), b"olope");
+	assert!(!slice_queue.has_remaining());
+}
+#[test] #[should_panic(expected = "`cnt` is greater than `self.len()`")]
+fn test_buf_advance() {
+	let mut slice_queue = SliceQueue::new();
+	slice_queue.push_from(b"Testolope").unwrap();
+	slice_queue.advance(10);
+}
+#[test]
+fn test_buf_mut() {
+	let mut slice_queue = SliceQueue::with_limit(12);
+	slice_queue.push_from(b"Test").unwrap();
+	
+	assert_eq!(slice_queue.remaining_mut(), 8);
+	slice_queue.put_u16(7);
+	slice_queue.put_slice(b"olope");
+	assert_eq!(&slice_queue[..], b"Test\x00\x07olope");
+	assert_eq!(slice_queue.remaining_mut(), 1);
+	assert_eq!(slice_queue.chunk_mut().len(), 1);
+	
+	slice_queue.put_u8(b'!');
+	assert!(!slice_queue.has_remaining_mut());
+	assert_eq!(slice_queue.chunk_mut().len(), 0);
+}
+#[test] #[should_panic(expected = "`cnt` is greater than the spare capacity")]
+fn test_buf_mut_advance_mut() {
+	let mut slice_queue = SliceQueue::<u8>::with_limit(4);
+	slice_queue.chunk_mut();
+	unsafe{ slice_queue.advance_mut(5) }
+}
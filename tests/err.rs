@@ -1,5 +1,7 @@
 extern crate slice_queue;
 use slice_queue::*;
+#[cfg(feature = "std")]
+use std::io::{ Read, Write, IoSlice, ErrorKind };
 
 
 #[test]
@@ -39,6 +41,14 @@ fn test_zero_limit_init() {
 	SliceQueue::<u8>::with_limit(0);
 }
 #[test] #[should_panic(expected = "`limit` is `0`")]
+fn test_zero_limit_init_with_capacity() {
+	SliceQueue::<u8>::with_capacity_and_limit(42, 0);
+}
+#[test] #[should_panic(expected = "`limit` is `0`")]
+fn test_zero_limit_from_iter() {
+	SliceQueue::from_iter_with_limit(0..7, 0);
+}
+#[test] #[should_panic(expected = "`limit` is `0`")]
 fn test_zero_limit_set() {
 	let mut slice_queue = SliceQueue::<u8>::new();
 	slice_queue.set_limit(0);
@@ -55,6 +65,56 @@ fn test_reserve() {
 	// Reserve 42 slots
 	assert_eq!(slice_queue.reserve_n(42).unwrap_err(), 9);
 	assert_eq!(slice_queue.reserved(), 9);
+	
+	// Push some data and reserve 42 slots again
+	slice_queue.push_from(b"Test").unwrap();
+	assert_eq!(slice_queue.reserve_n(42).unwrap_err(), 5);
+	assert_eq!(slice_queue.reserved(), 5);
+}
+#[test]
+fn test_reserve_limited() {
+	let mut slice_queue = SliceQueue::<u8>::with_limit(1024);
+	slice_queue.push_from(&[0; 1000]).unwrap();
+	slice_queue.shrink_to_fit();
+	
+	// Reserve more than the limit and verify that the capacity does not exceed the limit
+	assert_eq!(slice_queue.reserve_n(4096).unwrap_err(), 24);
+	assert_eq!(slice_queue.len() + slice_queue.reserved(), 1024);
+}
+#[test]
+fn test_reserve_after_pop() {
+	// Fill a slice-queue up to its limit and consume some elements
+	let mut slice_queue = SliceQueue::<u8>::with_capacity_and_limit(10, 10);
+	slice_queue.push_from(b"Testolope!").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Reserve more than the limit and verify that the capacity does not exceed the limit
+	assert_eq!(slice_queue.reserve_n(1000).unwrap_err(), 1);
+	assert_eq!(slice_queue.capacity(), 10);
+	
+	// Consume some more elements and try to reserve more than the limit
+	slice_queue.drop_n(2).unwrap();
+	assert_eq!(slice_queue.try_reserve_n(1000).unwrap().unwrap_err(), 3);
+	assert_eq!(slice_queue.capacity(), 10);
+	assert_eq!(slice_queue.reserved(), 3);
+	assert_eq!(&slice_queue[..], b"tolope!");
+}
+
+#[test]
+fn test_try_reserve() {
+	// Reserve more than the limit
+	let mut slice_queue = SliceQueue::<u8>::with_limit(9);
+	assert_eq!(slice_queue.try_reserve_n(42).unwrap().unwrap_err(), 9);
+	assert_eq!(slice_queue.reserved(), 9);
+	
+	// Reserve more than we can allocate
+	let mut slice_queue = SliceQueue::<u64>::new();
+	assert!(slice_queue.try_reserve_n(usize::MAX / 2).is_err());
+	assert_eq!(slice_queue.reserved(), 0);
+}
+#[test]
+fn test_try_with_capacity() {
+	assert!(SliceQueue::<u64>::try_with_capacity(usize::MAX / 2).is_err());
 }
 
 
@@ -64,10 +124,45 @@ fn test_peek() {
 	assert!(slice_queue.peek().is_none())
 }
 #[test]
+fn test_peek_mut() {
+	let mut slice_queue = SliceQueue::<u8>::new();
+	assert!(slice_queue.peek_mut().is_none());
+	assert!(slice_queue.peek_back().is_none());
+	assert!(slice_queue.peek_back_mut().is_none())
+}
+#[test]
+fn test_front_back() {
+	let mut slice_queue = SliceQueue::<u8>::new();
+	assert!(slice_queue.front().is_none());
+	assert!(slice_queue.front_mut().is_none());
+	assert!(slice_queue.back().is_none());
+	assert!(slice_queue.back_mut().is_none())
+}
+#[test]
 fn test_peek_n() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(slice_queue.peek_n(11).unwrap_err(), b"Testolope");
 }
+#[test]
+fn test_peek_n_mut() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert_eq!(slice_queue.peek_n_mut(11).unwrap_err(), b"Testolope");
+}
+#[test]
+fn test_peek_back_n() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert_eq!(slice_queue.peek_back_n(11).unwrap_err(), b"Testolope");
+	assert_eq!(slice_queue.peek_back_n_mut(11).unwrap_err(), b"Testolope");
+}
+#[test]
+fn test_copy_into() {
+	let slice_queue = SliceQueue::from(b"Test");
+	
+	let mut buffer = *b"Testolope";
+	buffer[..4].copy_from_slice(b"Gall");
+	assert_eq!(slice_queue.copy_into(&mut buffer), 4);
+	assert_eq!(&buffer, b"Testolope");
+}
 
 
 #[test]
@@ -89,6 +184,13 @@ fn test_pop_n() {
 	assert_eq!(slice_queue.pop_n(11).unwrap_err(), b"Testolope");
 }
 #[test]
+fn test_pop_n_append_to() {
+	let (mut slice_queue, mut dst) = (SliceQueue::from(b"Testolope"), b"!".to_vec());
+	assert!(!slice_queue.pop_n_append_to(10, &mut dst));
+	assert_eq!(dst, b"!");
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test]
 fn test_pop_into() {
 	let (mut slice_queue, mut target) = (SliceQueue::new(), [0u8; 11]);
 	assert_eq!(slice_queue.pop_into(&mut target).unwrap_err(), 0);
@@ -99,6 +201,26 @@ fn test_pop_into() {
 	assert_eq!(&target, b"Testolope\x00\x00");
 }
 #[test]
+fn test_pop_back() {
+	let mut slice_queue = SliceQueue::new();
+	assert_eq!(slice_queue.pop_back().unwrap_err(), ());
+	
+	// Push two elements, pop the first and consume two from the back
+	slice_queue.push_from(&[4, 7]).unwrap();
+	assert_eq!(slice_queue.pop().unwrap(), 4);
+	assert_eq!(slice_queue.pop_back().unwrap(), 7);
+	assert_eq!(slice_queue.pop_back().unwrap_err(), ());
+}
+#[test]
+fn test_pop_back_n() {
+	let mut slice_queue = SliceQueue::new();
+	assert!(slice_queue.pop_back_n(1).unwrap_err().is_empty());
+	
+	slice_queue.push_from(b"Testolope").unwrap();
+	assert_eq!(slice_queue.pop_back_n(11).unwrap_err(), b"Testolope");
+	assert!(slice_queue.is_empty());
+}
+#[test]
 fn test_drop_n() {
 	let mut slice_queue = SliceQueue::new();
 	assert_eq!(slice_queue.drop_n(1).unwrap_err(), 0);
@@ -109,6 +231,101 @@ fn test_drop_n() {
 }
 
 
+#[test] #[should_panic(expected = "`new_len` is larger than `self.limit`")]
+fn test_resize() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(b"Test").unwrap();
+	slice_queue.resize(9, b'!');
+}
+#[test] #[should_panic(expected = "`new_len` is larger than `self.limit`")]
+fn test_resize_with() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(b"Test").unwrap();
+	slice_queue.resize_with(9, || b'!');
+}
+#[test] #[should_panic(expected = "`mid` is greater than `self.len()`")]
+fn test_split_at() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope");
+	slice_queue.pop().unwrap();
+	slice_queue.split_at(10);
+}
+#[test] #[should_panic(expected = "`at` is greater than `self.len()`")]
+fn test_split_off() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	slice_queue.split_off(10);
+}
+#[test] #[should_panic(expected = "`self.limit` would be exceeded")]
+fn test_append() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(b"Test").unwrap();
+	slice_queue.append(&mut SliceQueue::from(b"olope".as_ref()));
+}
+#[test]
+fn test_try_append_resize_split_off() {
+	let (mut slice_queue, mut other) = (SliceQueue::with_limit(7), SliceQueue::from(b"olope"));
+	slice_queue.push_from(b"Test").unwrap();
+	
+	// Verify the errors and that nothing was modified
+	assert_eq!(slice_queue.try_append(&mut other).unwrap_err(), SliceQueueError::LimitExceeded{ limit: 7, requested: 9 });
+	assert_eq!(slice_queue.try_resize(8, b'!').unwrap_err(), SliceQueueError::LimitExceeded{ limit: 7, requested: 8 });
+	assert_eq!(slice_queue.try_split_off(5).unwrap_err(), SliceQueueError::NotEnoughElements{ have: 4, needed: 5 });
+	assert_eq!((&slice_queue[..], &other[..]), (b"Test".as_ref(), b"olope".as_ref()));
+	
+	// Verify the error messages
+	assert_eq!(slice_queue.try_append(&mut other).unwrap_err().to_string(), "9 elements requested but the limit is 7");
+	assert_eq!(slice_queue.try_split_off(5).unwrap_err().to_string(), "5 elements needed but only 4 are available");
+}
+#[test]
+fn test_insert() {
+	let mut slice_queue = SliceQueue::with_limit(4);
+	slice_queue.push_from(b"Tst").unwrap();
+	slice_queue.insert(1, b'e').unwrap();
+	assert_eq!(slice_queue.insert(0, b'!').unwrap_err(), b'!');
+	assert_eq!(&slice_queue[..], b"Test");
+}
+#[test] #[should_panic(expected = "`index` is greater than `self.len()`")]
+fn test_insert_out_of_bounds() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	slice_queue.insert(10, b'!').unwrap();
+}
+#[test] #[should_panic(expected = "`index` is greater or equal to `self.len()`")]
+fn test_remove() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	slice_queue.remove(9);
+}
+#[test] #[should_panic(expected = "`index` is greater or equal to `self.len()`")]
+fn test_swap_remove() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	slice_queue.swap_remove(9);
+}
+#[test] #[should_panic(expected = "`n` is greater than `self.len()`")]
+fn test_rotate_left() {
+	let mut slice_queue = SliceQueue::from(vec![1, 2, 3, 4, 5]);
+	slice_queue.rotate_left(6);
+}
+#[test] #[should_panic(expected = "`n` is greater than `self.len()`")]
+fn test_rotate_right() {
+	let mut slice_queue = SliceQueue::from(vec![1, 2, 3, 4, 5]);
+	slice_queue.rotate_right(6);
+}
+#[test] #[should_panic(expected = "chunk size must be non-zero")]
+fn test_rchunks() {
+	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	slice_queue.rchunks(0).count();
+}
+#[test] #[should_panic(expected = "window size must be non-zero")]
+fn test_windows() {
+	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	slice_queue.windows(0).count();
+}
+#[test] #[should_panic(expected = "`j` is greater or equal to `self.len()`")]
+fn test_swap() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	slice_queue.swap(0, 4);
+}
+
 #[test]
 fn test_push() {
 	let mut slice_queue = SliceQueue::with_limit(1);
@@ -130,7 +347,27 @@ fn test_push_from() {
 	assert_eq!(slice_queue.push_from(b"olope").unwrap_err(), 3);
 	assert_eq!(&slice_queue[..], b"Testolo");
 }
-#[test] #[should_panic(expected = "`self.len() + n` is larger than `self.limit`")]
+#[test]
+fn test_push_copy_from() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	assert_eq!(slice_queue.push_copy_from(b"Test").unwrap(), ());
+	assert_eq!(slice_queue.push_copy_from(b"olope").unwrap_err(), 3);
+	assert_eq!(&slice_queue[..], b"Testolo");
+}
+#[test]
+fn test_push_default() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(b"Test").unwrap();
+	assert_eq!(slice_queue.push_default(4).unwrap_err(), 3);
+	assert_eq!(&slice_queue[..], b"Test\0\0\0");
+}
+#[test] #[should_panic(expected = "`self.len()` exceeds `self.limit`")]
+fn test_push_overwrite_over_limit() {
+	let mut slice_queue = SliceQueue::from(b"Testo".as_ref());
+	slice_queue.set_limit(2);
+	slice_queue.push_overwrite(b'!');
+}
+#[test] #[should_panic(expected = "`self.limit` would be exceeded")]
 fn test_push_in_place_overpush() {
 	let mut slice_queue = SliceQueue::with_limit(7);
 	slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
@@ -138,6 +375,35 @@ fn test_push_in_place_overpush() {
 		Ok(9)
 	}).unwrap();
 }
+#[test] #[should_panic(expected = "`self.limit` would be exceeded")]
+fn test_get_or_extend() {
+	let mut slice_queue = SliceQueue::<u8>::with_limit(5);
+	slice_queue.get_or_extend(5);
+}
+#[test] #[should_panic(expected = "`self.limit` would be exceeded")]
+fn test_push_in_place_overflow() {
+	let mut slice_queue = SliceQueue::new();
+	slice_queue.push_from(b"Test").unwrap();
+	
+	// Verify that `self.len() + n` does not overflow
+	assert_eq!(slice_queue.try_push_in_place(usize::MAX, |_: &mut[u8]| -> Result<usize, &'static str> {
+		panic!("`push_fn` must not be called")
+	}).unwrap_err(), usize::MAX - 4);
+	slice_queue.push_in_place(usize::MAX, |_: &mut[u8]| -> Result<usize, &'static str> {
+		panic!("`push_fn` must not be called")
+	}).unwrap();
+}
+#[test]
+fn test_try_push_in_place_overpush() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(b"Test").unwrap();
+	
+	// Verify that nothing is pushed and `push_fn` is not called
+	assert_eq!(slice_queue.try_push_in_place(4, |_: &mut[u8]| -> Result<usize, &'static str> {
+		panic!("`push_fn` must not be called")
+	}).unwrap_err(), 3);
+	assert_eq!(&slice_queue[..], b"Test");
+}
 #[test] #[should_panic(expected = "`push_fn` must not claim that it pushed more elements than `n`")]
 fn test_push_in_place_invalid_retval() {
 	let mut slice_queue = SliceQueue::with_limit(7);
@@ -148,6 +414,122 @@ fn test_push_in_place_invalid_retval() {
 }
 
 
+#[test]
+fn test_find() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	assert!(slice_queue.find(b"!").is_none());
+	assert!(slice_queue.find(b"Testolope!").is_none());
+	assert!(slice_queue.find(b"lopeT").is_none());
+	assert!(SliceQueue::new().find(b"Test").is_none());
+}
+
+
+#[test] #[should_panic(expected = "`self.limit` would be exceeded")]
+fn test_extend() {
+	let mut slice_queue = SliceQueue::<u8>::with_limit(7);
+	slice_queue.extend(b"Testolope");
+}
+
+
+#[test] #[cfg(feature = "std")]
+fn test_read() {
+	let mut slice_queue = SliceQueue::new();
+	
+	// Read from an empty slice-queue
+	let mut buffer = [0u8; 11];
+	assert_eq!(slice_queue.read(&mut buffer).unwrap(), 0);
+	assert_eq!(buffer, [0u8; 11]);
+	
+	// Underread and validate
+	slice_queue.push_from(b"Testolope").unwrap();
+	assert_eq!(slice_queue.read(&mut buffer).unwrap(), 9);
+	assert_eq!(&buffer, b"Testolope\x00\x00");
+	
+	// `read_exact` must fail if there is not enough data available
+	slice_queue.push_from(b"Test").unwrap();
+	assert_eq!(slice_queue.read_exact(&mut buffer).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+#[test] #[cfg(feature = "std")]
+fn test_write() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	
+	// Overwrite and validate
+	assert_eq!(slice_queue.write(b"Testolope").unwrap(), 7);
+	assert_eq!(slice_queue.write(b"!!").unwrap(), 0);
+	assert_eq!(&slice_queue[..], b"Testolo");
+	
+	// `write_all` must fail if the limit is reached
+	slice_queue.drop_n(4).unwrap();
+	assert_eq!(slice_queue.write_all(b"Testolope").unwrap_err().kind(), ErrorKind::WriteZero);
+	assert_eq!(&slice_queue[..], b"oloTest");
+}
+#[test] #[cfg(feature = "std")]
+fn test_write_vectored() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	
+	// Write until the limit is reached and validate
+	let bufs = [IoSlice::new(b"Test"), IoSlice::new(b"olope")];
+	assert_eq!(slice_queue.write_vectored(&bufs).unwrap(), 7);
+	assert_eq!(slice_queue.write_vectored(&bufs).unwrap(), 0);
+	assert_eq!(&slice_queue[..], b"Testolo");
+}
+#[test] #[cfg(feature = "std")]
+fn test_drain_to() {
+	let (mut slice_queue, mut writer) = (SliceQueue::from(b"Testolope"), SliceQueue::with_limit(4));
+	
+	// Drain until `writer` is full and verify that only the written bytes were consumed
+	assert_eq!(slice_queue.drain_to(&mut writer).unwrap_err().kind(), ErrorKind::WriteZero);
+	assert_eq!(&writer[..], b"Test");
+	assert_eq!(&slice_queue[..], b"olope");
+}
+#[test] #[cfg(feature = "std")]
+fn test_fill_from() {
+	let mut slice_queue = SliceQueue::with_limit(4);
+	let mut reader = b"Testolope".as_ref();
+	
+	// Fill the slice-queue and verify that a full slice-queue is distinguishable from EOF
+	assert_eq!(slice_queue.fill_from(&mut reader, 42).unwrap(), 4);
+	let error = slice_queue.fill_from(&mut reader, 42).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::Other);
+	assert_eq!(
+		error.get_ref().unwrap().downcast_ref::<SliceQueueError>(),
+		Some(&SliceQueueError::LimitExceeded{ limit: 4, requested: 46 })
+	);
+	assert_eq!(&slice_queue[..], b"Test");
+	assert_eq!(reader, b"olope");
+}
+#[test] #[cfg(feature = "std")]
+fn test_drain_to_invalid_count() {
+	// A writer that reports more bytes than it was given
+	struct LyingWriter;
+	impl Write for LyingWriter {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			Ok(buf.len() + 1)
+		}
+		fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+	}
+	
+	// Drain and verify that nothing was consumed
+	let mut slice_queue = SliceQueue::from(b"Testolope");
+	assert_eq!(slice_queue.drain_to(&mut LyingWriter).unwrap_err().kind(), ErrorKind::InvalidData);
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+
+
+#[test]
+fn test_get() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	assert!(slice_queue.get(9).is_none());
+	assert!(slice_queue.get(7..10).is_none());
+	assert!(slice_queue.get(..=9).is_none());
+	assert!(slice_queue.get_mut(9).is_none());
+	assert!(slice_queue.get_mut(10..).is_none());
+}
+
+
 #[test] #[should_panic(expected = "index out of bounds: the len is 8 but the index is 8")]
 fn test_index() {
 	let slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6, 7]);
@@ -170,17 +552,17 @@ fn test_index_slice_range_end() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[0..10], b"Testolope!");
 }
-#[test] #[should_panic(expected = "index 10 out of range for slice of length 9")]
+#[test] #[should_panic(expected = "range end index 9 out of range for slice of length 9")]
 fn test_index_slice_range_incl_begin() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[9..=9], b"!");
 }
-#[test] #[should_panic(expected = "index 10 out of range for slice of length 9")]
+#[test] #[should_panic(expected = "range end index 9 out of range for slice of length 9")]
 fn test_index_slice_range_incl_end() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[0..=9], b"Testolope!");
 }
-#[test] #[should_panic(expected = "slice index starts at 10 but ends at 9")]
+#[test] #[should_panic(expected = "range start index 10 out of range for slice of length 9")]
 fn test_index_slice_range_from() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[10..], b"!");
@@ -190,8 +572,36 @@ fn test_index_slice_range_to() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[..10], b"Testolope!");
 }
-#[test] #[should_panic(expected = "index 10 out of range for slice of length 9")]
+#[test] #[should_panic(expected = "range end index 9 out of range for slice of length 9")]
 fn test_index_slice_to_incl() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(&slice_queue[..=9], b"Testolope!");
+}
+#[test] #[should_panic(expected = "slice index starts at 3 but ends at 1")]
+fn test_index_slice_inverted() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	let (start, end) = (3, 1);
+	assert!(slice_queue.get(start..end).is_none());
+	assert_eq!(&slice_queue[start..end], b"");
+}
+#[test] #[should_panic(expected = "slice index starts at 3 but ends at 1")]
+fn test_drain_inverted() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	let (start, end) = (3, 1);
+	slice_queue.drain(start..end);
+}
+#[test] #[should_panic(expected = "out of range for slice of length 9")]
+fn test_index_slice_to_incl_max() {
+	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert_eq!(&slice_queue[0..=usize::MAX], b"Testolope");
+}
+#[test] #[should_panic(expected = "range end index 10 out of range for slice of length 9")]
+fn test_remove_range() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	slice_queue.remove_range(4..10);
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 extern crate slice_queue;
-use { slice_queue::*, std::{ rc::Rc, ops::Range } };
+use { slice_queue::*, std::{ rc::Rc, borrow::Cow, ops::Range, collections::{ HashSet, HashMap, VecDeque } } };
+#[cfg(feature = "std")]
+use std::io::{ Read, Write, IoSlice };
 
 
 struct RcVec(Vec<Rc<usize>>);
 impl RcVec {
 	pub fn new(n: usize) -> Self {
-		RcVec((0..n).map(|i| Rc::new(i)).collect())
+		RcVec((0..n).map(Rc::new).collect())
 	}
 	pub fn validate(&self, range: Range<usize>, expected: usize) {
 		self.0[range].iter().for_each(|rc| assert_eq!(Rc::strong_count(rc), expected))
@@ -13,6 +15,95 @@ impl RcVec {
 }
 
 
+/// A downstream queue that only implements the required trait methods
+struct MinimalQueue(Vec<u8>, usize);
+impl ReadableSliceQueue<u8> for MinimalQueue {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+	fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+	
+	fn peek(&self) -> Option<&u8> {
+		self.0.first()
+	}
+	fn peek_n(&self, n: usize) -> Result<&[u8], &[u8]> {
+		if n <= self.0.len() { Ok(&self.0[..n]) }
+			else { Err(&self.0) }
+	}
+	fn peek_n_mut(&mut self, n: usize) -> Result<&mut[u8], &mut[u8]> {
+		if n <= self.0.len() { Ok(&mut self.0[..n]) }
+			else { Err(&mut self.0) }
+	}
+	
+	fn pop(&mut self) -> Result<u8, ()> {
+		if self.0.is_empty() { Err(()) }
+			else { Ok(self.0.remove(0)) }
+	}
+	fn pop_n(&mut self, n: usize) -> Result<Vec<u8>, Vec<u8>> {
+		let len = std::cmp::min(self.0.len(), n);
+		let elements = self.0.drain(..len).collect();
+		if len == n { Ok(elements) }
+			else { Err(elements) }
+	}
+	fn pop_into(&mut self, dst: &mut[u8]) -> Result<(), usize> {
+		let len = self.copy_into(dst);
+		self.0.drain(..len);
+		if len == dst.len() { Ok(()) }
+			else { Err(len) }
+	}
+	fn pop_back(&mut self) -> Result<u8, ()> {
+		self.0.pop().ok_or(())
+	}
+	
+	fn drop_n(&mut self, n: usize) -> Result<(), usize> {
+		self.pop_n(n).map(|_| ()).map_err(|elements| elements.len())
+	}
+}
+impl WriteableSliceQueue<u8> for MinimalQueue {
+	fn remaining(&self) -> usize {
+		self.1 - self.0.len()
+	}
+	
+	fn reserve_n(&mut self, n: usize) -> Result<(), usize> {
+		let to_reserve = std::cmp::min(self.remaining(), n);
+		self.0.reserve_exact(to_reserve);
+		if to_reserve == n { Ok(()) }
+			else { Err(to_reserve) }
+	}
+	fn reserved(&self) -> usize {
+		self.0.capacity() - self.0.len()
+	}
+	
+	fn push(&mut self, element: u8) -> Result<(), u8> {
+		if self.is_full() { return Err(element) }
+		self.0.push(element);
+		Ok(())
+	}
+	fn push_n(&mut self, mut n: Vec<u8>) -> Result<(), Vec<u8>> {
+		let remaining = n.split_off(std::cmp::min(self.remaining(), n.len()));
+		self.0.append(&mut n);
+		if remaining.is_empty() { Ok(()) }
+			else { Err(remaining) }
+	}
+	fn push_from(&mut self, src: &[u8]) -> Result<(), usize> {
+		let len = std::cmp::min(self.remaining(), src.len());
+		self.0.extend_from_slice(&src[..len]);
+		if len == src.len() { Ok(()) }
+			else { Err(len) }
+	}
+	fn push_in_place<E>(&mut self, n: usize, mut push_fn: impl FnMut(&mut[u8]) -> Result<usize, E>) -> Result<usize, E> {
+		assert!(n <= self.remaining(), "`self.limit` would be exceeded");
+		let old_len = self.0.len();
+		self.0.resize(old_len + n, 0);
+		let result = push_fn(&mut self.0[old_len..]);
+		self.0.truncate(old_len + *result.as_ref().unwrap_or(&0));
+		result
+	}
+}
+
+
 #[test]
 fn test_limit() {
 	// Create a slice-queue with a predefined limit and verify it
@@ -37,6 +128,53 @@ fn test_limit() {
 	assert_eq!(slice_queue.len(), 1);
 	assert_eq!(slice_queue.remaining(), 3);
 }
+#[test]
+fn test_is_full() {
+	let mut slice_queue = SliceQueue::with_limit(4);
+	assert!(!slice_queue.is_full());
+	
+	slice_queue.push_from(b"Test").unwrap();
+	assert!(slice_queue.is_full());
+	
+	// Lower the limit below the length and raise it again
+	slice_queue.set_limit(2);
+	assert!(slice_queue.is_full());
+	slice_queue.set_unlimited();
+	assert!(!slice_queue.is_full());
+}
+#[test]
+fn test_unlimited() {
+	let mut slice_queue = SliceQueue::<u8>::new();
+	assert!(!slice_queue.is_limited());
+	
+	slice_queue.set_limit(9);
+	assert!(slice_queue.is_limited());
+	
+	slice_queue.set_unlimited();
+	assert!(!slice_queue.is_limited());
+	assert_eq!(slice_queue.limit(), usize::MAX);
+}
+#[test]
+fn test_default() {
+	let mut slice_queue = SliceQueue::default();
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	assert_eq!(slice_queue.auto_shrink_mode(), AutoShrinkMode::Opportunistic);
+	assert_eq!(slice_queue.shrink_threshold(), 4);
+	
+	slice_queue.push_from(b"Testolope").unwrap();
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test]
+fn test_with_capacity_and_limit() {
+	let slice_queue = SliceQueue::<u8>::with_capacity_and_limit(7, 9);
+	assert_eq!(slice_queue.limit(), 9);
+	assert_eq!(slice_queue.reserved(), 7);
+	
+	// Preallocate more than the limit and verify that the capacity is clamped
+	let slice_queue = SliceQueue::<u8>::with_capacity_and_limit(42, 9);
+	assert_eq!(slice_queue.limit(), 9);
+	assert_eq!(slice_queue.reserved(), 9);
+}
 
 
 #[test]
@@ -64,6 +202,57 @@ fn test_reserve() {
 	assert_eq!(slice_queue.reserved(), 51);
 }
 
+#[test]
+fn test_try_reserve() {
+	let mut slice_queue = SliceQueue::with_capacity(42);
+	slice_queue.push_from(b"Testolope").unwrap();
+	
+	// Reserve capacity for 42 elements and verify that we have enough space for 42 elements
+	slice_queue.try_reserve_n(42).unwrap().unwrap();
+	assert_eq!(slice_queue.reserved(), 42);
+}
+#[test]
+fn test_try_with_capacity() {
+	let slice_queue = SliceQueue::<u8>::try_with_capacity(42).unwrap();
+	assert_eq!(slice_queue.reserved(), 42);
+}
+#[test]
+fn test_spare_capacity_mut() {
+	let mut slice_queue = SliceQueue::with_limit(6);
+	slice_queue.push_from(b"Te").unwrap();
+	slice_queue.reserve_n(16).unwrap_err();
+	
+	// The spare capacity is limited by `self.remaining()`
+	let spare = slice_queue.spare_capacity_mut();
+	assert_eq!(spare.len(), 4);
+	b"stol".iter().zip(spare.iter_mut()).for_each(|(b, s)| { s.write(*b); });
+	
+	unsafe{ slice_queue.set_len(6) };
+	assert_eq!(&slice_queue[..], b"Testol");
+	assert!(slice_queue.spare_capacity_mut().is_empty());
+}
+#[test]
+fn test_capacity_for_limit() {
+	let mut slice_queue = SliceQueue::<u8>::with_limit(10);
+	slice_queue.reserve_n(1000).unwrap_err();
+	assert!(slice_queue.capacity() <= 10);
+	assert_eq!(slice_queue.capacity_for_limit(), slice_queue.capacity());
+	
+	// Lower the limit below the capacity
+	slice_queue.set_limit(4);
+	assert_eq!(slice_queue.capacity_for_limit(), 4);
+}
+#[test]
+fn test_load_factor() {
+	let mut slice_queue = SliceQueue::<u8>::new();
+	assert_eq!(slice_queue.load_factor(), 1.0);
+	
+	slice_queue.reserve_n(8).unwrap();
+	slice_queue.push_from(b"Te").unwrap();
+	assert_eq!(slice_queue.load_factor(), 0.25);
+	assert_eq!(slice_queue.reserved(), 6);
+}
+
 
 #[test]
 fn test_shrink_opportunistic() {
@@ -81,6 +270,37 @@ fn test_shrink_opportunistic() {
 	assert_eq!(slice_queue.reserved(), 0);
 }
 #[test]
+fn test_shrink_threshold() {
+	let mut slice_queue = SliceQueue::from(vec![0u8; 14]);
+	assert_eq!(slice_queue.shrink_threshold(), 4);
+	
+	// A small queue is shrunk if the threshold is lowered
+	slice_queue.set_shrink_threshold(0);
+	slice_queue.drop_n(11).unwrap();
+	assert_eq!(slice_queue.len(), 3);
+	assert_eq!(slice_queue.reserved(), 0);
+	
+	// A high threshold effectively disables opportunistic shrinking
+	let mut slice_queue = SliceQueue::from(vec![0u8; 14]);
+	slice_queue.set_shrink_threshold(usize::MAX);
+	slice_queue.drop_n(7).unwrap();
+	assert_eq!(slice_queue.len(), 7);
+	assert_eq!(slice_queue.reserved(), 7);
+	
+	// Derived slice-queues inherit the threshold and the auto-shrink mode
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	let cloned = slice_queue.clone();
+	assert_eq!((cloned.shrink_threshold(), cloned.auto_shrink_mode()), (usize::MAX, AutoShrinkMode::Disabled));
+	let split = slice_queue.split_off(4);
+	assert_eq!((split.shrink_threshold(), split.auto_shrink_mode()), (usize::MAX, AutoShrinkMode::Disabled));
+	let transformed = slice_queue.transform(u16::from);
+	assert_eq!((transformed.shrink_threshold(), transformed.auto_shrink_mode()), (usize::MAX, AutoShrinkMode::Disabled));
+	
+	let mut cloned = SliceQueue::new();
+	cloned.clone_from(&split);
+	assert_eq!((cloned.shrink_threshold(), cloned.auto_shrink_mode()), (usize::MAX, AutoShrinkMode::Disabled));
+}
+#[test]
 fn test_shrink_to_fit() {
 	let mut slice_queue = SliceQueue::from(vec![0u8; 14]);
 	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Aggressive);
@@ -95,6 +315,36 @@ fn test_shrink_to_fit() {
 	assert_eq!(slice_queue.len(), 7);
 	assert_eq!(slice_queue.reserved(), 0);
 }
+#[test]
+fn test_shrink_disabled() {
+	let mut slice_queue = SliceQueue::from(vec![0u8; 14]);
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	assert_eq!(slice_queue.auto_shrink_mode(), AutoShrinkMode::Disabled);
+	
+	// Consume elements and verify that the capacity is kept
+	slice_queue.drop_n(6).unwrap();
+	slice_queue.pop().unwrap();
+	slice_queue.truncate(2);
+	assert_eq!(slice_queue.len(), 2);
+	assert_eq!(slice_queue.reserved(), 12);
+}
+#[test]
+fn test_shrink_to() {
+	let mut slice_queue = SliceQueue::<u8>::with_capacity(42);
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	slice_queue.push_from(b"!Testolope").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Shrink to a capacity larger than the length
+	slice_queue.shrink_to(14);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	assert_eq!(slice_queue.reserved(), 5);
+	
+	// Shrink to a capacity smaller than the length
+	slice_queue.shrink_to(4);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	assert_eq!(slice_queue.reserved(), 0);
+}
 
 
 #[test]
@@ -103,11 +353,106 @@ fn test_peek() {
 	assert_eq!(slice_queue.peek().unwrap(), &b'T');
 }
 #[test]
+fn test_peek_mut() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	*slice_queue.peek_mut().unwrap() = b'F';
+	assert_eq!(&slice_queue[..], b"Festolope");
+}
+#[test]
+fn test_peek_back() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert_eq!(slice_queue.peek_back().unwrap(), &b'e');
+	
+	// Pop the first element and verify that the last element is unchanged
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.peek_back().unwrap(), &b'e');
+}
+#[test]
+fn test_peek_back_mut() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	*slice_queue.peek_back_mut().unwrap() = b'a';
+	assert_eq!(&slice_queue[..], b"Testolopa");
+}
+#[test]
+fn test_front_back() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert_eq!(slice_queue.front(), Some(&b'T'));
+	assert_eq!(slice_queue.back(), Some(&b'e'));
+	
+	// Modify the first and last element
+	*slice_queue.front_mut().unwrap() = b'F';
+	*slice_queue.back_mut().unwrap() = b'a';
+	assert_eq!(&slice_queue[..], b"Festolopa");
+}
+#[test]
+fn test_split_first_last() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.split_first(), Some((&b'T', b"estolope".as_ref())));
+	assert_eq!(slice_queue.split_last(), Some((&b'e', b"Testolop".as_ref())));
+	
+	// Modify the first and last element and the remaining elements
+	let (first, remaining) = slice_queue.split_first_mut().unwrap();
+	*first = b'F';
+	remaining[0] = b'a';
+	let (last, remaining) = slice_queue.split_last_mut().unwrap();
+	*last = b'a';
+	remaining[1] = b'e';
+	assert_eq!(&slice_queue[..], b"Festolopa");
+	
+	// Split an empty slice-queue
+	slice_queue.clear();
+	assert!(slice_queue.split_first().is_none());
+	assert!(slice_queue.split_last_mut().is_none());
+}
+#[test]
+fn test_split_at() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope");
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.split_at(4), (b"Test".as_ref(), b"olope".as_ref()));
+	assert_eq!(slice_queue.split_at(9), (b"Testolope".as_ref(), b"".as_ref()));
+	
+	// Modify both halves
+	let (head, tail) = slice_queue.split_at_mut(4);
+	head.copy_from_slice(b"Gall");
+	tail[4] = b'a';
+	assert_eq!(&slice_queue[..], b"Gallolopa");
+}
+#[test]
 fn test_peek_n() {
 	let slice_queue = SliceQueue::from(b"Testolope".as_ref());
 	assert_eq!(slice_queue.peek_n(4).unwrap(), b"Test");
 	assert_eq!(slice_queue.peek_n(9).unwrap(), b"Testolope");
 }
+#[test]
+fn test_peek_n_mut() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	slice_queue.peek_n_mut(4).unwrap().copy_from_slice(b"Gall");
+	assert_eq!(slice_queue.peek_n_mut(9).unwrap(), b"Gallolope");
+}
+#[test]
+fn test_peek_back_n() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope");
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.peek_back_n(4).unwrap(), b"lope");
+	assert_eq!(slice_queue.peek_back_n(9).unwrap(), b"Testolope");
+	
+	slice_queue.peek_back_n_mut(4).unwrap().copy_from_slice(b"Gall");
+	assert_eq!(slice_queue.peek_back_n_mut(9).unwrap(), b"TestoGall");
+}
+#[test]
+fn test_copy_into() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope");
+	slice_queue.pop().unwrap();
+	
+	// Copy the first elements and verify that nothing was consumed
+	let mut buffer = [0; 4];
+	assert_eq!(slice_queue.copy_into(&mut buffer), 4);
+	assert_eq!(&buffer, b"Test");
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
 
 
 #[test]
@@ -120,6 +465,69 @@ fn test_pop() {
 	assert_eq!(slice_queue.len(), 7);
 }
 #[test]
+fn test_pop_push_interleaved() {
+	let mut slice_queue = SliceQueue::new();
+	
+	// Push and pop elements alternately and validate the order
+	(0..4).for_each(|i| slice_queue.push(i).unwrap());
+	(4..1_000_000).for_each(|i| {
+		slice_queue.push(i).unwrap();
+		assert_eq!(slice_queue.pop().unwrap(), i - 4);
+	});
+	assert_eq!(slice_queue.len(), 4);
+	(0..4).for_each(|i| assert_eq!(slice_queue[i], 999_996 + i));
+	assert_eq!(&slice_queue[1..3], &[999_997, 999_998]);
+}
+#[test] #[cfg(feature = "unsafe_fast_code")]
+fn test_pop_push_large_backlog() {
+	// Fill the queue up to its capacity
+	let mut slice_queue = SliceQueue::with_capacity(65_536);
+	(0..65_536).for_each(|i| slice_queue.push(i).unwrap());
+	
+	// Pop and push elements alternately and validate that the elements are only rarely moved
+	// (moving them on each pair would make every pop/push pair `O(n)`)
+	let mut moves = 0;
+	(65_536..165_536).for_each(|i| {
+		let ptr = slice_queue.as_slice().as_ptr();
+		assert_eq!(slice_queue.pop().unwrap(), i - 65_536);
+		slice_queue.push(i).unwrap();
+		if slice_queue.as_slice().as_ptr() != ptr.wrapping_add(1) { moves += 1 }
+	});
+	assert!(moves <= 8);
+	
+	// Validate the elements and that the capacity stays bounded
+	assert_eq!(slice_queue.len(), 65_536);
+	(0..65_536).for_each(|i| assert_eq!(slice_queue[i], 100_000 + i));
+	assert!(slice_queue.capacity() <= 2 * 65_536);
+}
+#[test]
+fn test_pop_push_reserved() {
+	let mut slice_queue = SliceQueue::with_capacity(10);
+	slice_queue.push_from(b"Testolope!").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Push `reserved()` elements and validate that this does not reallocate
+	let capacity = slice_queue.capacity();
+	(0..slice_queue.reserved()).for_each(|_| slice_queue.push(b'!').unwrap());
+	assert_eq!(slice_queue.capacity(), capacity);
+	assert_eq!(&slice_queue[..9], b"estolope!");
+}
+#[test]
+fn test_pop_drop() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	
+	// Pop some elements, drop the slice and validate ref-counts
+	(0..3).for_each(|i| assert_eq!(*slice_queue.pop().unwrap(), i));
+	base.validate(0..3, 1);
+	base.validate(3..14, 2);
+	
+	drop(slice_queue);
+	base.validate(0..14, 1);
+}
+#[test]
 fn test_pop_n() {
 	// Create elements and slice
 	let base = RcVec::new(14);
@@ -138,6 +546,23 @@ fn test_pop_n() {
 	base.validate(0..14, 2);
 }
 #[test]
+fn test_pop_n_max() {
+	let mut slice_queue = SliceQueue::from(b"Testolope");
+	assert_eq!(slice_queue.pop_n_max(4), b"Test");
+	assert_eq!(slice_queue.pop_n_max(7), b"olope");
+	assert!(slice_queue.pop_n_max(7).is_empty());
+}
+#[test]
+fn test_pop_n_append_to() {
+	let (mut slice_queue, mut dst) = (SliceQueue::from(b"Testolope"), Vec::new());
+	
+	// Append two batches to the same vector
+	assert!(slice_queue.pop_n_append_to(4, &mut dst));
+	assert!(slice_queue.pop_n_append_to(3, &mut dst));
+	assert_eq!(dst, b"Testolo");
+	assert_eq!(&slice_queue[..], b"pe");
+}
+#[test]
 fn test_pop_into() {
 	// Create buffer and base and slice
 	let (buffer_base, base) = (RcVec::new(7), RcVec::new(14));
@@ -159,6 +584,30 @@ fn test_pop_into() {
 	base.validate(0..14, 2);
 }
 #[test]
+fn test_pop_back() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6]);
+	
+	// Pop the last 4 elements and validate the popped elements and remaining length
+	(3..7).rev().for_each(|i| assert_eq!(slice_queue.pop_back().unwrap(), i));
+	assert_eq!(slice_queue.len(), 3);
+	assert_eq!(&slice_queue[..], &[0, 1, 2]);
+}
+#[test]
+fn test_pop_back_n() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Pop the last 7 elements and validate the popped and remaining elements
+	let popped = slice_queue.pop_back_n(7).unwrap();
+	assert_eq!(slice_queue.len(), 7);
+	(0..7).for_each(|i| assert_eq!(*popped[i], i + 7));
+	(0..7).for_each(|i| assert_eq!(*slice_queue[i], i));
+	
+	// Validate ref-counts
+	base.validate(0..14, 2);
+}
+#[test]
 fn test_drop_n() {
 	// Create elements and slice
 	let base = RcVec::new(14);
@@ -177,87 +626,524 @@ fn test_drop_n() {
 	base.validate(7..14, 2);
 }
 
-
 #[test]
-fn test_push() {
-	let mut slice_queue = SliceQueue::new();
+fn test_clear() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.pop().unwrap();
+	
+	// Clear the slice and validate the capacity and ref-counts
+	slice_queue.clear();
 	assert!(slice_queue.is_empty());
+	assert_eq!(slice_queue.reserved(), 14);
+	base.validate(0..14, 1);
+}
+
+#[test]
+fn test_truncate() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.pop().unwrap();
 	
-	(0..7).for_each(|i| slice_queue.push(i).unwrap());
-	assert_eq!(slice_queue.len(), 7);
+	// Truncate to a greater length and verify that nothing happened
+	slice_queue.truncate(42);
+	assert_eq!(slice_queue.len(), 13);
 	
-	(0..7).for_each(|i| assert_eq!(slice_queue[i], i));
+	// Truncate and validate the remaining elements and the ref-counts
+	slice_queue.truncate(6);
+	assert_eq!(slice_queue.len(), 6);
+	(0..6).for_each(|i| assert_eq!(*slice_queue[i], i + 1));
+	base.validate(0..1, 1);
+	base.validate(1..7, 2);
+	base.validate(7..14, 1);
 }
 #[test]
-fn test_push_n() {
-	let mut slice_queue = SliceQueue::new();
-	assert!(slice_queue.is_empty());
-	
-	// Push data and verify it
-	slice_queue.push_n(b"Testolope".to_vec()).unwrap();
-	assert_eq!(slice_queue.len(), 9);
-	assert_eq!(&slice_queue[..], b"Testolope");
+fn test_trim_to() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
 	
-	// Empty push
-	slice_queue.push_n(Vec::new()).unwrap();
-	assert_eq!(slice_queue.len(), 9);
-	assert_eq!(&slice_queue[..], b"Testolope");
+	// Keep the last 4 elements and validate the ref-counts
+	slice_queue.trim_to(4);
+	assert_eq!(slice_queue.len(), 4);
+	assert_eq!(*slice_queue[0], 10);
+	base.validate(0..10, 1);
+	base.validate(10..14, 2);
 	
-	// And a last push
-	slice_queue.push_n(b"!!".to_vec()).unwrap();
-	assert_eq!(slice_queue.len(), 11);
-	assert_eq!(&slice_queue[..], b"Testolope!!");
+	slice_queue.trim_to(7);
+	assert_eq!(slice_queue.len(), 4);
 }
+
 #[test]
-fn test_push_from() {
-	let mut slice_queue = SliceQueue::new();
-	assert!(slice_queue.is_empty());
+fn test_resize() {
+	let mut slice_queue = SliceQueue::from(b"!Test".as_ref());
+	slice_queue.pop().unwrap();
 	
-	// Push data and verify it
-	slice_queue.push_from(b"Testolope").unwrap();
-	assert_eq!(slice_queue.len(), 9);
-	assert_eq!(&slice_queue[..], b"Testolope");
+	// Grow and shrink
+	slice_queue.resize(7, b'!');
+	assert_eq!(&slice_queue[..], b"Test!!!");
+	slice_queue.resize(2, b'!');
+	assert_eq!(&slice_queue[..], b"Te");
+}
+#[test]
+fn test_resize_with() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0[..7].to_vec());
 	
-	// Empty push
-	slice_queue.push_from(b"").unwrap();
-	assert_eq!(slice_queue.len(), 9);
-	assert_eq!(&slice_queue[..], b"Testolope");
+	// Grow and shrink and validate the ref-counts
+	let mut i = 6;
+	slice_queue.resize_with(14, || { i += 1; base.0[i].clone() });
+	base.validate(0..14, 2);
 	
-	// And a last push
-	slice_queue.push_from(b"!!").unwrap();
-	assert_eq!(slice_queue.len(), 11);
-	assert_eq!(&slice_queue[..], b"Testolope!!");
+	slice_queue.resize_with(3, || unreachable!());
+	base.validate(0..3, 2);
+	base.validate(3..14, 1);
 }
 #[test]
-fn test_push_in_place() {
-	let mut slice_queue = SliceQueue::new();
+fn test_split_off() {
+	let mut slice_queue = SliceQueue::with_limit(42);
+	slice_queue.push_from(b"!Testolope").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Split the slice and validate both parts
+	let tail = slice_queue.split_off(4);
+	assert_eq!(&slice_queue[..], b"Test");
+	assert_eq!(&tail[..], b"olope");
+	assert_eq!(tail.limit(), 42);
+	
+	// Split at the bounds
+	assert!(slice_queue.split_off(4).is_empty());
+	assert_eq!(&slice_queue.split_off(0)[..], b"Test");
 	assert!(slice_queue.is_empty());
+}
+
+#[test]
+fn test_append() {
+	let mut slice_queue = SliceQueue::from(b"Test".as_ref());
+	let mut other = SliceQueue::with_capacity(42);
+	other.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	other.push_from(b"!olope").unwrap();
+	other.pop().unwrap();
 	
-	// Push data and verify it
-	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
-		assert_eq!(s.len(), 9);
-		s.copy_from_slice(b"Testolope");
-		Ok(9)
-	}).unwrap(), 9);
-	assert_eq!(slice_queue.len(), 9);
+	// Append `other` and validate both slices
+	slice_queue.append(&mut other);
 	assert_eq!(&slice_queue[..], b"Testolope");
+	assert!(other.is_empty());
+	assert_eq!(other.reserved(), 42);
+}
+#[test]
+fn test_try_append_resize_split_off() {
+	let (mut slice_queue, mut other) = (SliceQueue::with_limit(9), SliceQueue::from(b"olope"));
+	slice_queue.push_from(b"Test").unwrap();
 	
-	// Empty push
-	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
-		assert_eq!(s.len(), 9);
-		Ok(0)
-	}).unwrap(), 0);
-	assert_eq!(slice_queue.len(), 9);
+	slice_queue.try_append(&mut other).unwrap();
 	assert_eq!(&slice_queue[..], b"Testolope");
+	assert_eq!(&slice_queue.try_split_off(4).unwrap()[..], b"olope");
+	slice_queue.try_resize(7, b'!').unwrap();
+	assert_eq!(&slice_queue[..], b"Test!!!");
+}
+
+#[test]
+fn test_insert() {
+	let mut slice_queue = SliceQueue::from(b"!Tstolop".as_ref());
+	slice_queue.pop().unwrap();
 	
-	// Error push
-	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
-		assert_eq!(s.len(), 9);
-		s.copy_from_slice(b"Testolope");
-		Err("Some test error")
-	}).unwrap_err(), "Some test error");
-	assert_eq!(slice_queue.len(), 9);
-	assert_eq!(&slice_queue[..], b"Testolope");
+	// Insert elements in the middle and at the end
+	slice_queue.insert(1, b'e').unwrap();
+	slice_queue.insert(8, b'e').unwrap();
+	slice_queue.insert(0, b'>').unwrap();
+	assert_eq!(&slice_queue[..], b">Testolope");
+}
+#[test]
+fn test_remove() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.pop().unwrap();
+	
+	// Remove elements and validate the remaining elements and the ref-counts
+	assert_eq!(*slice_queue.remove(12), 13);
+	assert_eq!(*slice_queue.remove(3), 4);
+	assert_eq!(*slice_queue.remove(0), 1);
+	assert_eq!(slice_queue.len(), 10);
+	(0..2).for_each(|i| assert_eq!(*slice_queue[i], i + 2));
+	(2..10).for_each(|i| assert_eq!(*slice_queue[i], i + 3));
+	
+	base.validate(0..2, 1);
+	base.validate(4..5, 1);
+	base.validate(13..14, 1);
+}
+#[test]
+fn test_remove_first() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.pop().unwrap();
+	
+	// Remove the first occurrence and validate the remaining elements and the ref-counts
+	assert!(slice_queue.remove_first(&base.0[4]));
+	assert_eq!(slice_queue.len(), 12);
+	(0..3).for_each(|i| assert_eq!(*slice_queue[i], i + 1));
+	(3..12).for_each(|i| assert_eq!(*slice_queue[i], i + 2));
+	base.validate(4..5, 1);
+	
+	// Remove a missing element
+	assert!(!slice_queue.remove_first(&base.0[0]));
+	assert!(!slice_queue.remove_first(&Rc::new(42)));
+	assert_eq!(slice_queue.len(), 12);
+	
+	// Only the first occurrence is removed
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert!(slice_queue.remove_first(&b'o'));
+	assert_eq!(&slice_queue[..], b"Testlope");
+}
+
+#[test]
+fn test_swap_remove() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6]);
+	slice_queue.pop().unwrap();
+	
+	// Remove an element and verify that the last element took its place
+	assert_eq!(slice_queue.swap_remove(1), 2);
+	assert_eq!(&slice_queue[..], &[1, 6, 3, 4, 5]);
+	
+	// Remove the last element
+	assert_eq!(slice_queue.swap_remove(4), 5);
+	assert_eq!(&slice_queue[..], &[1, 6, 3, 4]);
+}
+
+#[test]
+fn test_retain() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.retain(|i| i % 2 == 0);
+	assert_eq!(&slice_queue[..], &[2, 4]);
+}
+#[test]
+fn test_retain_mut() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	
+	// Decrement the elements and drop the expired ones
+	slice_queue.retain_mut(|i| { *i -= 1; *i > 0 });
+	assert_eq!(&slice_queue[..], &[1, 2, 3]);
+}
+#[test]
+fn test_retain_drop() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Retain the odd elements and validate the ref-counts
+	slice_queue.retain(|i| **i % 2 == 1);
+	assert_eq!(slice_queue.len(), 7);
+	(0..14).for_each(|i| assert_eq!(Rc::strong_count(&base.0[i]), 1 + i % 2));
+}
+
+#[test]
+fn test_dedup() {
+	let mut slice_queue = SliceQueue::from(b"!Teeestoolooope!!".as_ref());
+	slice_queue.pop().unwrap();
+	
+	slice_queue.dedup();
+	assert_eq!(&slice_queue[..], b"Testolope!");
+}
+#[test]
+fn test_dedup_by_key() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Deduplicate pairs and validate the remaining elements and the ref-counts
+	slice_queue.dedup_by_key(|i| **i / 2);
+	assert_eq!(slice_queue.len(), 7);
+	(0..7).for_each(|i| assert_eq!(*slice_queue[i], i * 2));
+	(0..14).for_each(|i| assert_eq!(Rc::strong_count(&base.0[i]), 2 - i % 2));
+}
+#[test]
+fn test_drain() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6]);
+	slice_queue.pop().unwrap();
+	
+	// Drain from both ends and validate the remaining elements
+	let mut drain = slice_queue.drain(1..5);
+	assert_eq!(drain.len(), 4);
+	assert_eq!(drain.next(), Some(2));
+	assert_eq!(drain.next_back(), Some(5));
+	drop(drain);
+	assert_eq!(&slice_queue[..], &[1, 6]);
+	
+	// Drain everything
+	assert_eq!(slice_queue.drain(..).collect::<Vec<_>>(), [1, 6]);
+	assert!(slice_queue.is_empty());
+}
+#[test]
+fn test_remove_range() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope");
+	slice_queue.pop().unwrap();
+	
+	assert_eq!(slice_queue.remove_range(2..5), b"sto");
+	assert_eq!(slice_queue.remove_range(..=1), b"Te");
+	assert_eq!(&slice_queue[..], b"lope");
+}
+#[test]
+fn test_drain_drop() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Drop a partially consumed drain and validate the ref-counts
+	slice_queue.drain(3..=9).next().unwrap();
+	assert_eq!(slice_queue.len(), 7);
+	base.validate(0..3, 2);
+	base.validate(3..10, 1);
+	base.validate(10..14, 2);
+}
+
+#[test]
+fn test_push_overwrite() {
+	let mut slice_queue = SliceQueue::with_limit(3);
+	
+	// Push until the limit is reached and validate that nothing was evicted
+	(0..3).for_each(|i| assert!(slice_queue.push_overwrite(i).is_none()));
+	assert_eq!(&slice_queue[..], &[0, 1, 2]);
+	
+	// Overwrite elements and validate the evicted elements
+	(3..7).for_each(|i| assert_eq!(slice_queue.push_overwrite(i), Some(i - 3)));
+	assert_eq!(&slice_queue[..], &[4, 5, 6]);
+}
+#[test]
+fn test_push_from_iter() {
+	let mut slice_queue = SliceQueue::with_limit(5);
+	slice_queue.push_from(&[0, 1]).unwrap();
+	
+	// Push until the limit is reached and validate that the remaining elements are not consumed
+	let mut iter = 2..9;
+	assert_eq!(slice_queue.push_from_iter(iter.by_ref()), 3);
+	assert_eq!(&slice_queue[..], &[0, 1, 2, 3, 4]);
+	assert_eq!(iter.next(), Some(5));
+	
+	// Push into the full queue
+	assert_eq!(slice_queue.push_from_iter(iter), 0);
+	assert_eq!(&slice_queue[..], &[0, 1, 2, 3, 4]);
+}
+#[test]
+fn test_extend_within_limit() {
+	let mut slice_queue = SliceQueue::with_limit(3);
+	
+	// Fill the queue and validate that the rejected elements are returned in order
+	assert_eq!(slice_queue.extend_within_limit(0..5), [3, 4]);
+	assert_eq!(&slice_queue[..], &[0, 1, 2]);
+	
+	// Extend the full queue and within the limit
+	assert_eq!(slice_queue.extend_within_limit(vec![5, 6]), [5, 6]);
+	slice_queue.pop().unwrap();
+	assert!(slice_queue.extend_within_limit(Some(7)).is_empty());
+	assert_eq!(&slice_queue[..], &[1, 2, 7]);
+}
+
+#[test]
+fn test_rotate() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.rotate_left(2);
+	assert_eq!(&slice_queue[..], &[3, 4, 5, 1, 2]);
+	slice_queue.rotate_right(2);
+	assert_eq!(&slice_queue[..], &[1, 2, 3, 4, 5]);
+	slice_queue.rotate_right(1);
+	assert_eq!(&slice_queue[..], &[5, 1, 2, 3, 4]);
+	
+	// Full rotations
+	slice_queue.rotate_left(5);
+	slice_queue.rotate_right(0);
+	assert_eq!(&slice_queue[..], &[5, 1, 2, 3, 4]);
+}
+#[test]
+fn test_swap() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.swap(0, 3);
+	assert_eq!(&slice_queue[..], &[4, 2, 3, 1]);
+	slice_queue.swap(1, 1);
+	assert_eq!(&slice_queue[..], &[4, 2, 3, 1]);
+}
+#[test]
+fn test_reverse() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.reverse();
+	assert_eq!(&slice_queue[..], &[3, 2, 1]);
+}
+#[test]
+fn test_sort() {
+	let mut slice_queue = SliceQueue::from(vec![0, 4, 1, 3, 2]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.sort();
+	assert_eq!(&slice_queue[..], &[1, 2, 3, 4]);
+	slice_queue.sort_by(|a, b| b.cmp(a));
+	assert_eq!(&slice_queue[..], &[4, 3, 2, 1]);
+	slice_queue.sort_unstable();
+	assert_eq!(&slice_queue[..], &[1, 2, 3, 4]);
+	slice_queue.sort_by_key(|i| i % 2);
+	assert_eq!(&slice_queue[..], &[2, 4, 1, 3]);
+}
+
+#[test]
+fn test_extract_if() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6]);
+	slice_queue.pop().unwrap();
+	
+	let extracted: Vec<usize> = slice_queue.extract_if(|i| *i % 2 == 0).collect();
+	assert_eq!(extracted, [2, 4, 6]);
+	assert_eq!(&slice_queue[..], &[1, 3, 5]);
+}
+#[test]
+fn test_pop_while() {
+	let mut slice_queue = SliceQueue::from(b"  \tTestolope ".as_ref());
+	
+	// Consume the leading whitespace
+	assert_eq!(slice_queue.pop_while(|b| b.is_ascii_whitespace()), b"  \t");
+	assert_eq!(&slice_queue[..], b"Testolope ");
+	assert!(slice_queue.pop_while(|b| b.is_ascii_whitespace()).is_empty());
+	
+	// Consume all elements
+	assert_eq!(slice_queue.pop_while(|_| true), b"Testolope ");
+	assert!(slice_queue.is_empty());
+}
+#[test]
+fn test_discard_while() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Discard the leading elements and validate ref-counts
+	assert_eq!(slice_queue.discard_while(|i| **i < 4), 4);
+	assert_eq!(*slice_queue[0], 4);
+	base.validate(0..4, 1);
+	base.validate(4..14, 2);
+	assert_eq!(slice_queue.discard_while(|i| **i < 4), 0);
+}
+#[test]
+fn test_extract_if_drop() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	
+	// Extract the first two odd elements, drop the iterator and validate the ref-counts
+	slice_queue.extract_if(|i| **i % 2 == 1).take(2).for_each(drop);
+	assert_eq!(slice_queue.len(), 12);
+	base.validate(0..1, 2);
+	base.validate(1..2, 1);
+	base.validate(2..3, 2);
+	base.validate(3..4, 1);
+	base.validate(4..14, 2);
+}
+
+
+#[test]
+fn test_push() {
+	let mut slice_queue = SliceQueue::new();
+	assert!(slice_queue.is_empty());
+	
+	(0..7).for_each(|i| slice_queue.push(i).unwrap());
+	assert_eq!(slice_queue.len(), 7);
+	
+	(0..7).for_each(|i| assert_eq!(slice_queue[i], i));
+}
+#[test]
+fn test_push_n() {
+	let mut slice_queue = SliceQueue::new();
+	assert!(slice_queue.is_empty());
+	
+	// Push data and verify it
+	slice_queue.push_n(b"Testolope".to_vec()).unwrap();
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// Empty push
+	slice_queue.push_n(Vec::new()).unwrap();
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// And a last push
+	slice_queue.push_n(b"!!".to_vec()).unwrap();
+	assert_eq!(slice_queue.len(), 11);
+	assert_eq!(&slice_queue[..], b"Testolope!!");
+}
+#[test]
+fn test_push_from() {
+	let mut slice_queue = SliceQueue::new();
+	assert!(slice_queue.is_empty());
+	
+	// Push data and verify it
+	slice_queue.push_from(b"Testolope").unwrap();
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// Empty push
+	slice_queue.push_from(b"").unwrap();
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// And a last push
+	slice_queue.push_from(b"!!").unwrap();
+	assert_eq!(slice_queue.len(), 11);
+	assert_eq!(&slice_queue[..], b"Testolope!!");
+}
+#[test]
+fn test_push_copy_from() {
+	let mut slice_queue = SliceQueue::new();
+	slice_queue.push_copy_from(b"!Test").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Push more data and verify it
+	slice_queue.push_copy_from(b"olope").unwrap();
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test]
+fn test_push_default() {
+	let mut slice_queue = SliceQueue::from(b"Test");
+	slice_queue.push_default(4).unwrap();
+	assert_eq!(&slice_queue[..], b"Test\0\0\0\0");
+}
+#[test]
+fn test_push_in_place() {
+	let mut slice_queue = SliceQueue::new();
+	assert!(slice_queue.is_empty());
+	
+	// Push data and verify it
+	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
+		assert_eq!(s.len(), 9);
+		s.copy_from_slice(b"Testolope");
+		Ok(9)
+	}).unwrap(), 9);
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// Empty push
+	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
+		assert_eq!(s.len(), 9);
+		Ok(0)
+	}).unwrap(), 0);
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	
+	// Error push
+	assert_eq!(slice_queue.push_in_place(9, |s: &mut[u8]| -> Result<usize, &'static str> {
+		assert_eq!(s.len(), 9);
+		s.copy_from_slice(b"Testolope");
+		Err("Some test error")
+	}).unwrap_err(), "Some test error");
+	assert_eq!(slice_queue.len(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
 	
 	// Another non-empty push
 	assert_eq!(slice_queue.push_in_place(42, |s: &mut[u8]| -> Result<usize, &'static str> {
@@ -268,6 +1154,566 @@ fn test_push_in_place() {
 	assert_eq!(slice_queue.len(), 11);
 	assert_eq!(&slice_queue[..], b"Testolope!!");
 }
+#[test]
+fn test_try_push_in_place() {
+	let mut slice_queue = SliceQueue::with_limit(9);
+	
+	// Push data and verify it
+	assert_eq!(slice_queue.try_push_in_place(4, |s: &mut[u8]| -> Result<usize, &'static str> {
+		s.copy_from_slice(b"Test");
+		Ok(4)
+	}).unwrap().unwrap(), 4);
+	assert_eq!(&slice_queue[..], b"Test");
+	
+	// Error push
+	assert_eq!(slice_queue.try_push_in_place(4, |_: &mut[u8]| -> Result<usize, &'static str> {
+		Err("Some test error")
+	}).unwrap().unwrap_err(), "Some test error");
+	assert_eq!(&slice_queue[..], b"Test");
+}
+
+
+#[test]
+fn test_iter() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.iter().count(), slice_queue.len());
+	
+	// Modify the elements and validate them
+	slice_queue.iter_mut().for_each(|i| *i += 7);
+	slice_queue.iter().enumerate().for_each(|(i, e)| assert_eq!(*e, i + 8));
+}
+#[test]
+fn test_map_in_place() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.pop().unwrap();
+	
+	slice_queue.map_in_place(|i| *i += 1);
+	assert_eq!(&slice_queue[..], &[2, 3, 4, 5]);
+}
+#[test]
+fn test_chunks() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	let chunks: Vec<&[u8]> = slice_queue.chunks(4).collect();
+	assert_eq!(chunks, [b"Test".as_ref(), b"olop", b"e"]);
+	
+	let mut chunks_exact = slice_queue.chunks_exact(4);
+	assert_eq!(chunks_exact.by_ref().collect::<Vec<_>>(), [b"Test".as_ref(), b"olop"]);
+	assert_eq!(chunks_exact.remainder(), b"e");
+}
+#[test]
+fn test_rchunks() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	let rchunks: Vec<&[u8]> = slice_queue.rchunks(4).collect();
+	assert_eq!(rchunks, [b"lope".as_ref(), b"esto", b"T"]);
+}
+#[test]
+fn test_windows() {
+	let mut slice_queue = SliceQueue::from(b"!Test".as_ref());
+	slice_queue.pop().unwrap();
+	
+	let windows: Vec<&[u8]> = slice_queue.windows(3).collect();
+	assert_eq!(windows, [b"Tes".as_ref(), b"est"]);
+	assert_eq!(slice_queue.windows(5).count(), 0);
+}
+#[test]
+fn test_reader() {
+	let mut slice_queue = SliceQueue::from(b"!\x04Testolope");
+	slice_queue.pop().unwrap();
+	
+	// Read a length-prefixed frame speculatively
+	let mut reader = slice_queue.reader();
+	assert_eq!(reader.peek(), Some(&4));
+	let len = reader.read_n(1).unwrap()[0] as usize;
+	assert_eq!(reader.read_n(len).unwrap(), b"Test");
+	assert_eq!((reader.position(), reader.remaining()), (5, 5));
+	assert_eq!(reader.read_n(7).unwrap_err(), b"olope");
+	assert!(reader.peek().is_none());
+	
+	// Commit the first frame
+	assert_eq!(slice_queue.len(), 10);
+	slice_queue.drop_n(5).unwrap();
+	assert_eq!(&slice_queue[..], b"olope");
+}
+#[test]
+fn test_contains() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	assert!(slice_queue.contains(&b'T'));
+	assert!(slice_queue.contains(&b'p'));
+	
+	slice_queue.pop().unwrap();
+	assert!(!slice_queue.contains(&b'T'));
+	assert!(!slice_queue.contains(&b'!'));
+}
+#[test]
+fn test_position() {
+	let mut slice_queue = SliceQueue::from(b"Test\r\nolope".as_ref());
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.position(|b| *b == b'\r'), Some(3));
+	assert_eq!(slice_queue.position(|b| *b == b'!'), None);
+}
+#[test]
+fn test_find() {
+	let mut slice_queue = SliceQueue::from(b"!Test\r\nolope\r\n".as_ref());
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.find(b"\r\n"), Some(4));
+	assert_eq!(slice_queue.find(b"Test\r\nolope\r\n"), Some(0));
+	assert_eq!(slice_queue.find(b"olope\r\n"), Some(6));
+	assert_eq!(slice_queue.find(b""), Some(0));
+	
+	// Extract a line
+	let line = slice_queue.pop_n(slice_queue.find(b"\r\n").unwrap() + 2).unwrap();
+	assert_eq!(line, b"Test\r\n");
+}
+#[test]
+fn test_count_matching() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4, 5, 6]);
+	slice_queue.pop().unwrap();
+	
+	assert_eq!(slice_queue.count_matching(|i| i % 2 == 0), 3);
+	assert_eq!(slice_queue.count_matching(|i| *i > 6), 0);
+	assert_eq!(slice_queue.len(), 6);
+}
+#[test]
+fn test_starts_with_ends_with() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Matching and non-matching prefixes/suffixes (relative to the live elements)
+	assert!(slice_queue.starts_with(b"Test"));
+	assert!(!slice_queue.starts_with(b"!Test"));
+	assert!(slice_queue.ends_with(b"lope"));
+	assert!(!slice_queue.ends_with(b"lopes"));
+	
+	// The empty needle always matches
+	assert!(slice_queue.starts_with(b""));
+	assert!(slice_queue.ends_with(b""));
+	assert!(SliceQueue::<u8>::new().starts_with(b""));
+	assert!(!SliceQueue::<u8>::new().ends_with(b"T"));
+}
+#[test]
+fn test_binary_search() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 3, 5, 7]);
+	slice_queue.pop().unwrap();
+	
+	assert_eq!(slice_queue.binary_search(&5), Ok(2));
+	assert_eq!(slice_queue.binary_search(&4), Err(2));
+	assert_eq!(slice_queue.binary_search(&0), Err(0));
+	assert_eq!(slice_queue.binary_search_by(|i| i.cmp(&7)), Ok(3));
+	assert_eq!(slice_queue.binary_search_by(|i| i.cmp(&8)), Err(4));
+}
+#[test]
+fn test_partition_point() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 3, 5, 5, 7]);
+	slice_queue.pop().unwrap();
+	
+	// Count the elements older than a timestamp and discard them
+	let older = slice_queue.partition_point(|i| *i < 5);
+	assert_eq!(older, 2);
+	slice_queue.drop_n(older).unwrap();
+	assert_eq!(&slice_queue[..], &[5, 5, 7]);
+	
+	assert_eq!(slice_queue.partition_point(|i| *i < 0), 0);
+	assert_eq!(slice_queue.partition_point(|i| *i < 8), 3);
+}
+#[test]
+fn test_into_iter() {
+	// Create elements and slice
+	let base = RcVec::new(14);
+	let mut slice_queue = SliceQueue::from(base.0.clone());
+	slice_queue.pop().unwrap();
+	
+	// Consume some elements from both ends and drop the iterator
+	let mut iter = slice_queue.into_iter();
+	assert_eq!(iter.len(), 13);
+	assert_eq!(*iter.next().unwrap(), 1);
+	assert_eq!(*iter.next_back().unwrap(), 13);
+	assert_eq!(iter.len(), 11);
+	
+	drop(iter);
+	base.validate(0..14, 1);
+}
+#[test]
+fn test_into_iter_ref() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3]);
+	slice_queue.pop().unwrap();
+	
+	// Iterate over mutable and immutable references
+	for i in &mut slice_queue { *i *= 2 }
+	let collected: Vec<usize> = (&slice_queue).into_iter().cloned().collect();
+	assert_eq!(collected, [2, 4, 6]);
+	
+	// Consume the slice
+	let collected: Vec<usize> = slice_queue.into_iter().collect();
+	assert_eq!(collected, [2, 4, 6]);
+}
+
+
+#[test]
+fn test_extend() {
+	let mut slice_queue = SliceQueue::with_limit(9);
+	
+	// Extend the slice by values and by references
+	slice_queue.extend(b"Test".iter().cloned());
+	slice_queue.extend(b"olope");
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test]
+fn test_from_iter() {
+	let slice_queue: SliceQueue<usize> = (0..7).collect();
+	assert_eq!(slice_queue.len(), 7);
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	(0..7).for_each(|i| assert_eq!(slice_queue[i], i));
+}
+#[test]
+fn test_from_iter_with_limit() {
+	// Collect within the limit
+	let slice_queue = SliceQueue::from_iter_with_limit((0..7).map(|i| i * 2), 7);
+	assert_eq!(slice_queue.limit(), 7);
+	(0..7).for_each(|i| assert_eq!(slice_queue[i], i * 2));
+	
+	// Collect more elements than the limit and validate that the surplus is truncated
+	let mut iter = 0..7;
+	let slice_queue = SliceQueue::from_iter_with_limit(iter.by_ref().filter(|i| i % 2 == 1), 2);
+	assert_eq!(&slice_queue[..], &[1, 3]);
+	assert_eq!(iter.next(), Some(4));
+}
+#[test]
+fn test_from_slice() {
+	// Create elements and slice
+	let base = RcVec::new(7);
+	let slice_queue = SliceQueue::from(base.0.as_slice());
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	base.validate(0..7, 2);
+	
+	// Create a slice-queue from a literal
+	let slice_queue = SliceQueue::from(b"Testolope");
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test]
+fn test_from_slices() {
+	let slice_queue = SliceQueue::from_slices(&[b"Test", b"", b"olope"]);
+	assert_eq!(&slice_queue[..], b"Testolope");
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	assert_eq!(slice_queue.reserved(), 0);
+}
+#[test]
+fn test_vec_deque_round_trip() {
+	// Create a wrapped `VecDeque` so that its elements are not contiguous
+	let base = RcVec::new(7);
+	let mut vec_deque = VecDeque::with_capacity(7);
+	base.0[3..].iter().cloned().for_each(|i| vec_deque.push_back(i));
+	base.0[..3].iter().rev().cloned().for_each(|i| vec_deque.push_front(i));
+	
+	// Convert it into a `SliceQueue` and verify the order and ref-counts
+	let mut slice_queue = SliceQueue::from(vec_deque);
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	(0..7).for_each(|i| assert_eq!(*slice_queue[i], i));
+	base.validate(0..7, 2);
+	
+	// Convert it back and verify that nothing was cloned
+	slice_queue.pop().unwrap();
+	let vec_deque = VecDeque::from(slice_queue);
+	assert_eq!(vec_deque.len(), 6);
+	vec_deque.iter().enumerate().for_each(|(i, rc)| assert_eq!(**rc, i + 1));
+	base.validate(0..1, 1);
+	base.validate(1..7, 2);
+}
+
+
+#[test] #[cfg(feature = "std")]
+fn test_read() {
+	let mut slice_queue = SliceQueue::from(b"Testolope".as_ref());
+	
+	// Read some data and verify it
+	let mut buffer = [0u8; 4];
+	assert_eq!(slice_queue.read(&mut buffer).unwrap(), 4);
+	assert_eq!(&buffer, b"Test");
+	
+	// Read the remaining data
+	let mut buffer = Vec::new();
+	assert_eq!(slice_queue.read_to_end(&mut buffer).unwrap(), 5);
+	assert_eq!(buffer, b"olope");
+	assert!(slice_queue.is_empty());
+}
+#[test] #[cfg(feature = "std")]
+fn test_write() {
+	let mut slice_queue = SliceQueue::new();
+	
+	// Write some data and verify it
+	assert_eq!(slice_queue.write(b"Testolope").unwrap(), 9);
+	write!(slice_queue, "!{}", 7).unwrap();
+	slice_queue.flush().unwrap();
+	assert_eq!(&slice_queue[..], b"Testolope!7");
+}
+#[test] #[cfg(feature = "std")]
+fn test_write_vectored() {
+	let mut slice_queue = SliceQueue::new();
+	
+	let bufs = [IoSlice::new(b"Test"), IoSlice::new(b""), IoSlice::new(b"olope")];
+	assert_eq!(slice_queue.write_vectored(&bufs).unwrap(), 9);
+	assert_eq!(&slice_queue[..], b"Testolope");
+}
+#[test] #[cfg(feature = "std")]
+fn test_drain_to() {
+	// A writer that performs short writes
+	struct ShortWriter(Vec<u8>);
+	impl Write for ShortWriter {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0.extend_from_slice(&buf[..buf.len().min(2)]);
+			Ok(buf.len().min(2))
+		}
+		fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+	}
+	
+	// Drain the bytes and verify them
+	let mut slice_queue = SliceQueue::from(b"Testolope");
+	let mut writer = ShortWriter(Vec::new());
+	assert_eq!(slice_queue.drain_to(&mut writer).unwrap(), 9);
+	assert_eq!(writer.0, b"Testolope");
+	assert!(slice_queue.is_empty());
+}
+#[test] #[cfg(feature = "std")]
+fn test_fill_from() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	let mut reader = b"Testolope".as_ref();
+	
+	// Fill the slice-queue until the limit is reached
+	assert_eq!(slice_queue.fill_from(&mut reader, 4).unwrap(), 4);
+	assert_eq!(slice_queue.fill_from(&mut reader, 42).unwrap(), 3);
+	assert_eq!(slice_queue.fill_from(&mut reader, 0).unwrap(), 0);
+	assert_eq!(&slice_queue[..], b"Testolo");
+	
+	// Read until EOF
+	slice_queue.drop_n(7).unwrap();
+	assert_eq!(slice_queue.fill_from(&mut reader, 42).unwrap(), 2);
+	assert_eq!(slice_queue.fill_from(&mut reader, 42).unwrap(), 0);
+	assert_eq!(&slice_queue[..], b"pe");
+}
+#[test] #[cfg(feature = "std")]
+fn test_fill_from_unbounded() {
+	let mut slice_queue = SliceQueue::new();
+	let data = vec![0x2a; 20_000];
+	let mut reader = data.as_slice();
+	
+	// Read with an unbounded `max` and verify that the reads are chunked
+	let mut total = 0;
+	loop {
+		match slice_queue.fill_from(&mut reader, usize::MAX).unwrap() {
+			0 => break,
+			read => total += read
+		}
+		assert!(slice_queue.capacity() < 1 << 20);
+	}
+	assert_eq!(total, 20_000);
+	assert_eq!(&slice_queue[..], data.as_slice());
+}
+
+
+#[test]
+fn test_eq() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(&[0, 1, 2, 3]).unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Compare against other containers
+	assert_eq!(slice_queue, SliceQueue::from(vec![1, 2, 3]));
+	assert_eq!(slice_queue, [1, 2, 3]);
+	assert_eq!(slice_queue, vec![1, 2, 3]);
+	assert_eq!(slice_queue, *[1, 2, 3].as_ref());
+	
+	assert_ne!(slice_queue, SliceQueue::from(vec![0, 1, 2, 3]));
+	assert_ne!(slice_queue, [1, 2]);
+}
+
+#[test]
+fn test_ord() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2]);
+	slice_queue.pop().unwrap();
+	
+	let (greater, prefix, longer) =
+		(SliceQueue::from(vec![1, 3]), SliceQueue::from(vec![1]), SliceQueue::from(vec![1, 2, 0]));
+	
+	// Compare queues lexicographically
+	assert!(slice_queue < greater);
+	assert!(slice_queue > prefix);
+	assert!(longer > slice_queue);
+	assert_eq!(slice_queue.cmp(&SliceQueue::from(vec![1, 2])), std::cmp::Ordering::Equal);
+	
+	// Sort queues
+	let mut sorted = vec![greater.clone(), slice_queue.clone(), prefix.clone()];
+	sorted.sort();
+	assert_eq!(sorted, [prefix, slice_queue, greater]);
+}
+#[test]
+fn test_hash() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Insert the slice into a set and look up a clone
+	let mut set = HashSet::new();
+	set.insert(slice_queue.clone());
+	assert!(set.contains(&slice_queue));
+	assert!(set.contains(&SliceQueue::from(b"Testolope".as_ref())));
+	assert!(!set.contains(&SliceQueue::from(b"Testolope!".as_ref())));
+}
+#[test]
+fn test_clone_from() {
+	let mut source = SliceQueue::with_limit(9);
+	source.push_from(b"!Test").unwrap();
+	source.pop().unwrap();
+	
+	// Clone into a slice-queue with enough capacity and verify that the capacity is reused
+	let mut slice_queue = SliceQueue::with_capacity(42);
+	slice_queue.push_from(b"Testolope").unwrap();
+	slice_queue.clone_from(&source);
+	assert_eq!(&slice_queue[..], b"Test");
+	assert_eq!(slice_queue.limit(), 9);
+	assert_eq!(slice_queue.reserved(), 38);
+}
+#[test]
+fn test_debug() {
+	let mut slice_queue = SliceQueue::with_limit(7);
+	slice_queue.push_from(&[0, 1, 2, 3]).unwrap();
+	slice_queue.pop().unwrap();
+	
+	assert_eq!(format!("{:?}", slice_queue), "SliceQueue { len: 3, limit: 7, elements: [1, 2, 3] }");
+}
+
+
+#[test]
+fn test_as_slice() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	assert_eq!(slice_queue.as_slice(), b"Testolope");
+	
+	slice_queue.as_mut_slice()[0] = b'F';
+	assert_eq!(slice_queue.as_slice(), b"Festolope");
+}
+#[test]
+fn test_as_cow() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	slice_queue.pop().unwrap();
+	
+	// The view borrows the live elements even if consumed elements precede them
+	match slice_queue.as_cow() {
+		Cow::Borrowed(slice) => assert_eq!(slice.as_ptr(), slice_queue.as_slice().as_ptr()),
+		Cow::Owned(_) => panic!("`as_cow` cloned the elements")
+	}
+	assert_eq!(&*slice_queue.as_cow(), b"Testolope");
+}
+#[test]
+fn test_make_contiguous() {
+	let mut slice_queue = SliceQueue::from((0..14).collect::<Vec<_>>());
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	slice_queue.drop_n(3).unwrap();
+	
+	// Compact the elements and verify that the slice covers all elements
+	let slice = slice_queue.make_contiguous();
+	assert_eq!(slice.len(), 11);
+	slice[0] = 42;
+	assert_eq!(&slice_queue[..2], &[42, 4]);
+	assert_eq!(slice_queue.as_slice().as_ptr(), slice_queue.make_contiguous().as_ptr());
+}
+#[test]
+fn test_into_vec() {
+	let mut slice_queue = SliceQueue::from(vec![0, 1, 2, 3, 4]);
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	slice_queue.pop().unwrap();
+	
+	let vec = slice_queue.into_vec();
+	assert_eq!(vec, [1, 2, 3, 4]);
+	assert_eq!(vec.capacity(), 5);
+}
+#[test]
+fn test_take_all() {
+	let mut slice_queue = SliceQueue::with_capacity(42);
+	slice_queue.push_from(&[0, 1, 2, 3, 4]).unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Take the elements and verify that the capacity is kept
+	assert_eq!(slice_queue.take_all(), [1, 2, 3, 4]);
+	assert!(slice_queue.is_empty());
+	assert_eq!(slice_queue.reserved(), 42);
+}
+#[test]
+fn test_transform() {
+	let mut slice_queue = SliceQueue::with_limit(9);
+	slice_queue.push_from(b"!Testolope").unwrap_err();
+	slice_queue.pop().unwrap();
+	
+	let slice_queue = slice_queue.transform(char::from);
+	assert_eq!(slice_queue.iter().collect::<String>(), "Testolop");
+	assert_eq!(slice_queue.limit(), 9);
+	assert_eq!(slice_queue.reserved(), 0);
+}
+#[test]
+fn test_as_ref() {
+	fn first(x: impl AsRef<[u8]>) -> u8 { x.as_ref()[0] }
+	fn zero_first(mut x: impl AsMut<[u8]>) { x.as_mut()[0] = 0 }
+	
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	assert_eq!(first(&slice_queue), b'T');
+	
+	zero_first(&mut slice_queue);
+	assert_eq!(&slice_queue[..], b"\x00estolope");
+}
+#[test]
+fn test_get() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Get elements and subslices
+	assert_eq!(slice_queue.get(4), Some(&b'o'));
+	assert_eq!(slice_queue.get(..4), Some(b"Test".as_ref()));
+	assert_eq!(slice_queue.get(4..=6), Some(b"olo".as_ref()));
+	
+	// Modify elements and subslices
+	*slice_queue.get_mut(0).unwrap() = b'F';
+	slice_queue.get_mut(4..).unwrap().copy_from_slice(b"ivals");
+	assert_eq!(&slice_queue[..], b"Festivals");
+}
+#[test]
+#[cfg(feature = "unsafe_fast_code")]
+fn test_get_unchecked() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// The index is relative to the live elements
+	assert_eq!(unsafe{ *slice_queue.get_unchecked(0) }, b'T');
+	assert_eq!(unsafe{ *slice_queue.get_unchecked(8) }, b'e');
+	
+	unsafe{ *slice_queue.get_unchecked_mut(0) = b'F' };
+	assert_eq!(&slice_queue[..], b"Festolope");
+}
+#[test]
+fn test_get_or_extend() {
+	let mut slice_queue = SliceQueue::new();
+	
+	// Write to an index behind the end and to an existing index
+	*slice_queue.get_or_extend(5) = 7;
+	assert_eq!(slice_queue.len(), 6);
+	*slice_queue.get_or_extend(1) = 4;
+	assert_eq!(&slice_queue[..], &[0, 4, 0, 0, 0, 7]);
+}
+
+#[test]
+fn test_borrow() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Insert the slice into a map and look it up by a borrowed slice
+	let mut map = HashMap::new();
+	map.insert(slice_queue, 7);
+	assert_eq!(map.get(b"Testolope".as_ref()), Some(&7));
+	assert_eq!(map.get(b"!Testolope".as_ref()), None);
+}
 
 
 #[test]
@@ -301,6 +1747,30 @@ fn test_index_slice() {
 	assert_eq!(&slice_queue[..=6], b"Testolo");
 }
 #[test]
+fn test_index_slice_inclusive() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Test inclusive ranges relative to the front
+	assert_eq!(&slice_queue[1..=3], b"est");
+	assert_eq!(&slice_queue[..=0], b"T");
+	assert_eq!(slice_queue.get(1..=3), Some(b"est".as_ref()));
+	assert_eq!(slice_queue.get(..=0), Some(b"T".as_ref()));
+	assert_eq!(slice_queue.drain(1..=3).collect::<Vec<_>>(), b"est");
+	assert_eq!(slice_queue.drain(..=0).collect::<Vec<_>>(), b"T");
+	assert_eq!(&slice_queue[..], b"olope");
+}
+#[test]
+fn test_index_slice_inclusive_end() {
+	let mut slice_queue = SliceQueue::from(b"!Testolope".as_ref());
+	slice_queue.pop().unwrap();
+	
+	// Verify that inclusive ends are translated to `end + 1`
+	assert_eq!(&slice_queue[0..=2], &slice_queue[0..3]);
+	assert_eq!(&slice_queue[..=2], &slice_queue[..3]);
+	assert_eq!(&slice_queue[6..=8], &slice_queue[6..9]);
+}
+#[test]
 fn test_index_slice_mut() {
 	let mut slice_queue = SliceQueue::from(b"*********".as_ref());
 	macro_rules! copy_test_reset {
@@ -329,3 +1799,49 @@ fn test_index_slice_mut() {
 	copy_test_reset!(&mut slice_queue[4..=6], b"olo");
 	copy_test_reset!(&mut slice_queue[..=6], b"Testolo");
 }
+
+
+#[test]
+fn test_trait_defaults() {
+	let mut queue = MinimalQueue(Vec::new(), 9);
+	
+	// Validate the default implementations of `WriteableSliceQueue`
+	assert!(!queue.is_full());
+	assert_eq!(queue.try_reserve_n(4).unwrap(), Ok(()));
+	assert_eq!(queue.try_reserve_n(42).unwrap(), Err(9));
+	assert_eq!(queue.try_push_in_place(10, |_| -> Result<usize, ()> { Ok(10) }), Err(9));
+	assert_eq!(queue.try_push_in_place(9, |buffer| -> Result<usize, ()> { Ok(buffer.len()) }), Ok(Ok(9)));
+	assert!(queue.is_full());
+	queue.drop_n(9).unwrap();
+	assert_eq!(queue.push_copy_from(b"Testolope!"), Err(9));
+	assert_eq!(queue.pop_n(9).unwrap(), b"Testolope");
+	assert_eq!(queue.push_default(4), Ok(()));
+	assert_eq!(queue.push_default(7), Err(5));
+	assert_eq!(queue.pop_n(9).unwrap(), [0; 9]);
+	
+	// Validate the default implementations of `ReadableSliceQueue`
+	queue.push_from(b"Testolope").unwrap();
+	*queue.peek_mut().unwrap() = b'F';
+	assert_eq!(queue.peek_back(), Some(&b'e'));
+	*queue.peek_back_mut().unwrap() = b'E';
+	assert_eq!(queue.peek_back_n(3).unwrap(), b"opE");
+	assert_eq!(queue.peek_back_n(10).unwrap_err(), b"FestolopE");
+	queue.peek_back_n_mut(2).unwrap().copy_from_slice(b"pe");
+	assert_eq!(queue.peek_back_n_mut(10).unwrap_err(), b"Festolope");
+	*queue.peek_back_mut().unwrap() = b'E';
+	let mut buffer = *b"0123456789";
+	assert_eq!(queue.copy_into(&mut buffer[..4]), 4);
+	assert_eq!(queue.copy_into(&mut buffer), 9);
+	assert_eq!(&buffer, b"FestolopE9");
+	assert_eq!(queue.pop_back_n(2).unwrap(), b"pE");
+	assert_eq!(queue.pop_n_max(2), b"Fe");
+	assert_eq!(queue.pop_back_n(9).unwrap_err(), b"stolo");
+	queue.push_from(b"Testolope").unwrap();
+	let mut dst = b"!".to_vec();
+	assert!(queue.pop_n_append_to(4, &mut dst));
+	assert!(!queue.pop_n_append_to(6, &mut dst));
+	assert_eq!(dst, b"!Test");
+	assert_eq!(queue.pop_n_max(42), b"olope");
+	assert!(queue.peek_mut().is_none());
+	assert!(queue.peek_back_mut().is_none());
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "serde")]
+extern crate slice_queue;
+extern crate serde_json;
+use slice_queue::*;
+
+
+#[test]
+fn test_serialize() {
+	let mut slice_queue = SliceQueue::with_limit(42);
+	slice_queue.push_from(&[0, 1, 2, 3]).unwrap();
+	slice_queue.pop().unwrap();
+	
+	assert_eq!(serde_json::to_string(&slice_queue).unwrap(), "[1,2,3]");
+}
+#[test]
+fn test_deserialize() {
+	let slice_queue: SliceQueue<u8> = serde_json::from_str("[1,2,3]").unwrap();
+	assert_eq!(&slice_queue[..], &[1, 2, 3]);
+	assert_eq!(slice_queue.limit(), usize::MAX);
+	assert_eq!(slice_queue.reserved(), 0);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "zeroize")]
+extern crate slice_queue;
+extern crate zeroize;
+use { slice_queue::*, zeroize::{ Zeroize, Zeroizing } };
+
+
+#[test]
+fn test_zeroize() {
+	let mut slice_queue = SliceQueue::with_capacity(16);
+	slice_queue.set_auto_shrink_mode(AutoShrinkMode::Disabled);
+	slice_queue.push_from(b"!Testolope").unwrap();
+	slice_queue.pop().unwrap();
+	
+	// Zeroize the queue and validate that the capacity is kept
+	slice_queue.zeroize();
+	assert!(slice_queue.is_empty());
+	assert_eq!(slice_queue.capacity(), 16);
+	
+	// Validate that the entire allocation (including the consumed element) was zeroed
+	let spare = slice_queue.spare_capacity_mut();
+	assert_eq!(spare.len(), 16);
+	spare.iter().for_each(|byte| assert_eq!(unsafe{ byte.assume_init() }, 0));
+}
+#[test]
+fn test_zeroizing() {
+	let mut slice_queue = Zeroizing::new(SliceQueue::new());
+	slice_queue.push_from(b"Testolope").unwrap();
+	assert_eq!(&slice_queue[..], b"Testolope");
+}